@@ -3,7 +3,9 @@ use std::{sync::Arc, time::Duration};
 use event_source::{emit, EventSource};
 use tokio::{spawn, time::sleep};
 
-fn spawn_emit_task(source: &Arc<EventSource!(&mut i32)>, value: i32, interval: Duration) {
+type Source = EventSource!(&mut i32);
+
+fn spawn_emit_task(source: &Arc<Source>, value: i32, interval: Duration) {
     spawn({
         let source = source.clone();
 
@@ -21,7 +23,7 @@ fn spawn_emit_task(source: &Arc<EventSource!(&mut i32)>, value: i32, interval: D
 
 #[tokio::main]
 async fn main() {
-    let source: Arc<EventSource!(&mut i32)> = Arc::new(EventSource::new());
+    let source: Arc<Source> = Arc::new(EventSource::new());
 
     spawn_emit_task(&source, 5, Duration::from_millis(300));
     spawn_emit_task(&source, 10, Duration::from_millis(1000));
@@ -0,0 +1,43 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::{sync::Arc, thread::sleep, time::Duration};
+
+use event_source::{emit, EventSource};
+use futures::{channel::oneshot, executor::ThreadPool};
+
+type Source = EventSource!(&mut i32);
+
+fn main() {
+    let pool = ThreadPool::new().unwrap();
+    let source: Arc<Source> = Arc::new(EventSource::new());
+
+    let (sender, receiver) = oneshot::channel();
+    pool.spawn_ok({
+        let mut sender = Some(sender);
+
+        // Owned future is 'static, so it can be spawned on any executor
+        source.on_owned(move |value, flow| {
+            if let Some(sender) = sender.take() {
+                let _ = sender.send(*value);
+            }
+
+            flow.set_done();
+        })
+    });
+
+    while source.listener_count() == 0 {
+        sleep(Duration::from_millis(10));
+    }
+
+    let mut value = 35;
+    emit!(source, &mut value);
+
+    println!(
+        "Received: {}",
+        futures::executor::block_on(receiver).unwrap()
+    );
+}
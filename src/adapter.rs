@@ -0,0 +1,993 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, VecDeque},
+    sync::{Arc, Weak},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::sync::mpsc::{self, Receiver};
+
+use core::{
+    any::Any,
+    future::{poll_fn, Future},
+    ops::Sub,
+    pin::pin,
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    task::Poll,
+    time::Duration,
+};
+
+use higher_kinded_types::ForLifetime;
+#[cfg(feature = "alloc")]
+use parking_lot::Mutex;
+
+use crate::{ControlFlow, EventFnFuture, EventSource};
+
+#[cfg(feature = "alloc")]
+/// Boxed listener for [`EventSource::race`]
+pub type RaceListener<'a, T, R> =
+    Box<dyn FnMut(<T as ForLifetime>::Of<'_>, &mut ControlFlow) -> Option<R> + Send + 'a>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Result of listener for [`EventSource::on_retrying`]
+pub enum RetryOutcome {
+    /// Event is handled
+    Done,
+
+    /// Event is not handled due to transient failure, call listener again
+    Retry,
+
+    /// Event is not handled, do not retry
+    GiveUp,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Transition of state machine for [`EventSource::on_state_machine`]
+pub enum StateStep<S, R> {
+    /// Continue with next state
+    Continue(S),
+
+    /// Reached terminal state with result
+    Done(R),
+}
+
+#[derive(Debug, Default)]
+/// Shared cancellation of listeners registered with [`EventSource::on_cancellable`]
+pub struct CancelToken {
+    source: EventSource<crate::__private::ForLt!(())>,
+}
+
+impl CancelToken {
+    /// Create new [`CancelToken`]
+    pub const fn new() -> Self {
+        Self {
+            source: EventSource::new(),
+        }
+    }
+
+    /// Cancel every listeners sharing this token and wake their tasks
+    pub fn cancel(&self) {
+        self.source.close();
+    }
+
+    /// Check if this token is cancelled
+    pub fn is_cancelled(&self) -> bool {
+        self.source.is_closed()
+    }
+}
+
+/// Source of current time for [`EventSource::on_expiring`] and [`EventSource::on_timed`]
+pub trait Clock {
+    /// Point of time
+    type Instant: Ord;
+
+    /// Current time
+    fn now(&self) -> Self::Instant;
+}
+
+impl<F: Fn() -> I, I: Ord> Clock for F {
+    type Instant = I;
+
+    fn now(&self) -> I {
+        self()
+    }
+}
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Listen events converted into `U` using [`From`]
+    pub fn on_into<U, F>(
+        &self,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        U: for<'a> From<T::Of<'a>>,
+        F: FnMut(U, &mut ControlFlow) + Send,
+    {
+        self.on(move |event, flow| listener(U::from(event), flow))
+    }
+
+    /// Listen events while `predicate` returns true
+    ///
+    /// Listener is finished on first event `predicate` returns false, without being called.
+    pub fn on_while<P, F>(
+        &self,
+        mut predicate: P,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        P: FnMut(&T::Of<'_>) -> bool + Send,
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if predicate(&event) {
+                listener(event, flow);
+            } else {
+                flow.set_done();
+            }
+        })
+    }
+
+    /// Listen events until `done_when` returns true
+    ///
+    /// `done_when` is checked after listener is called for each event.
+    pub fn on_until_predicate<P, F>(
+        &self,
+        mut done_when: P,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        P: FnMut() -> bool + Send,
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            listener(event, flow);
+            if done_when() {
+                flow.set_done();
+            }
+        })
+    }
+
+    /// Listen events which `should_intercept` returns true, stopping their propagation
+    ///
+    /// Other events are passed to next listeners without calling listener.
+    pub fn on_intercepting<P, F>(
+        &self,
+        mut should_intercept: P,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        P: FnMut(&T::Of<'_>) -> bool + Send,
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        self.on(move |event, flow| {
+            if flow.done() || !should_intercept(&event) {
+                return;
+            }
+
+            listener(event, flow);
+            flow.stop_propagation();
+        })
+    }
+
+    /// Listen events, letting listener stop propagation at most `quota` times in a row
+    ///
+    /// Once listener stopped propagation `quota` times in a row, its next stop is ignored and event is passed to next listeners.
+    pub fn on_with_quota<F>(
+        &self,
+        quota: usize,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let mut stopped = 0;
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            listener(event, flow);
+            if flow.propagation() {
+                stopped = 0;
+            } else if stopped == quota {
+                stopped = 0;
+                flow.resume_propagation();
+            } else {
+                stopped += 1;
+            }
+        })
+    }
+
+    /// Listen events in groups of `n`
+    ///
+    /// Listener is called with `n` every time `n` events are arrived.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero
+    pub fn on_coalesce<F>(
+        &self,
+        n: usize,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        F: FnMut(usize, &mut ControlFlow) + Send,
+    {
+        assert!(n > 0, "n must be greater than zero");
+
+        let mut count = 0;
+        self.on(move |_, flow| {
+            if flow.done() {
+                return;
+            }
+
+            count += 1;
+            if count == n {
+                count = 0;
+                listener(n, flow);
+            }
+        })
+    }
+
+    /// Listen events, waking task of returned future every `m` events
+    ///
+    /// Wakes are not coalesced, so task is woken every `m` events even if it is not polled in between.
+    /// Task is also woken once listener is finished.
+    ///
+    /// # Panics
+    /// Panics if `m` is zero
+    pub fn on_with_batched_wakes<F>(
+        &self,
+        m: usize,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        assert!(m > 0, "m must be greater than zero");
+
+        let mut count = 0;
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            listener(event, flow);
+
+            count += 1;
+            if count == m {
+                count = 0;
+                flow.force_wake();
+            }
+        })
+    }
+
+    /// Listen events with shared reference to `ctx`
+    pub fn on_with_ctx<'a, C, F>(
+        &'a self,
+        ctx: &'a C,
+        mut listener: F,
+    ) -> EventFnFuture<'a, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send + 'a, T>
+    where
+        C: Sync + ?Sized,
+        F: FnMut(&C, T::Of<'_>, &mut ControlFlow) + Send + 'a,
+    {
+        self.on(move |event, flow| listener(ctx, event, flow))
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Listen events with `state`, while it is alive
+    ///
+    /// Listener is finished without being called on first event arrived after `state` is dropped.
+    pub fn on_weak<S, F>(
+        &self,
+        state: Weak<S>,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        S: Send + Sync,
+        F: FnMut(&S, T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            match state.upgrade() {
+                Some(state) => listener(&state, event, flow),
+                None => flow.set_done(),
+            }
+        })
+    }
+
+    /// Listen events, calling `on_match` every time `matcher` returns [`Option::Some`]
+    #[doc(alias = "on_filter_map")]
+    pub fn on_each_match<M, F, R>(
+        &self,
+        mut matcher: M,
+        mut on_match: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        M: FnMut(T::Of<'_>) -> Option<R> + Send,
+        F: FnMut(R) + Send,
+    {
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if let Some(output) = matcher(event) {
+                on_match(output);
+            }
+        })
+    }
+
+    /// Listen events, skipping events with same key from `key_of` as previous event
+    pub fn on_distinct_by<K, G, F>(
+        &self,
+        key_of: G,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        K: PartialEq + Send,
+        G: Fn(&T::Of<'_>) -> K + Send,
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let mut prev = None;
+
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            let key = key_of(&event);
+            if prev.as_ref() == Some(&key) {
+                return;
+            }
+            prev = Some(key);
+
+            listener(event, flow);
+        })
+    }
+
+    /// Listen events until `deadline`
+    ///
+    /// Listener is finished without being called on first event arrived once `clock` reached `deadline`.
+    pub fn on_expiring<C, F>(
+        &self,
+        clock: C,
+        deadline: C::Instant,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        C: Clock + Send,
+        C::Instant: Send,
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if clock.now() >= deadline {
+                flow.set_done();
+            } else {
+                listener(event, flow);
+            }
+        })
+    }
+
+    /// Listen events, calling listener again with same event while it returns [`RetryOutcome::Retry`]
+    ///
+    /// Listener is called at most `max_retries + 1` times for each event, with event cloned each time.
+    pub fn on_retrying<F>(
+        &self,
+        max_retries: usize,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        for<'a> T::Of<'a>: Clone,
+        F: FnMut(T::Of<'_>, &mut ControlFlow) -> RetryOutcome + Send,
+    {
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            let mut retries = 0;
+            while let RetryOutcome::Retry = listener(event.clone(), flow) {
+                if retries == max_retries || flow.done() {
+                    break;
+                }
+
+                retries += 1;
+            }
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Listen events, returning output for each event
+    ///
+    /// Outputs are collected by [`EventSource::with_emitter_collect`] and dropped in other emissions.
+    pub fn on_returning<F, R>(
+        &self,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) -> R + Send,
+        R: 'static,
+    {
+        self.on(move |event, flow| {
+            let output = listener(event, flow);
+            flow.set_output(Box::new(output));
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Listen events with number of events arrived within last `window` including current one
+    ///
+    /// `clock` must be monotonic.
+    pub fn on_with_rate<C, F>(
+        &self,
+        clock: C,
+        window: Duration,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        C: Clock + Send,
+        C::Instant: Sub<Output = Duration> + Copy + Send,
+        F: FnMut(usize, T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let mut timestamps = VecDeque::new();
+
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            let now = clock.now();
+            while let Some(&front) = timestamps.front() {
+                if now - front < window {
+                    break;
+                }
+
+                timestamps.pop_front();
+            }
+            timestamps.push_back(now);
+
+            listener(timestamps.len(), event, flow);
+        })
+    }
+
+    /// Listen events, calling `report` with time taken by each listener call
+    ///
+    /// `clock` must be monotonic.
+    pub fn on_timed<C, F, R>(
+        &self,
+        clock: C,
+        mut listener: F,
+        mut report: R,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        C: Clock + Send,
+        C::Instant: Sub<Output = Duration>,
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+        R: FnMut(Duration) + Send,
+    {
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            let start = clock.now();
+            listener(event, flow);
+            report(clock.now() - start);
+        })
+    }
+
+    /// Listen events, driving state machine starting from `initial` with `step`
+    ///
+    /// Resolves with result once `step` returns [`StateStep::Done`], or [`Option::None`] if source is closed before.
+    pub async fn on_state_machine<S, F, R>(&self, initial: S, mut step: F) -> Option<R>
+    where
+        S: Send,
+        F: FnMut(S, T::Of<'_>) -> StateStep<S, R> + Send,
+        R: Send,
+    {
+        let mut state = Some(initial);
+
+        self.once(|event, _| match step(state.take()?, event) {
+            StateStep::Continue(next) => {
+                state = Some(next);
+                None
+            }
+
+            StateStep::Done(output) => Some(output),
+        })
+        .await
+    }
+
+    /// Listen events, calling `first` for the first event and `rest` for every later events
+    pub fn on_first_then<A, B>(
+        &self,
+        first: A,
+        mut rest: B,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        A: FnOnce(T::Of<'_>) + Send,
+        B: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let mut first = Some(first);
+
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            match first.take() {
+                Some(first) => first(event),
+                None => rest(event, flow),
+            }
+        })
+    }
+
+    /// Listen events, calling `cleanup` once listener is finished or its future is dropped
+    ///
+    /// `cleanup` is called exactly once.
+    pub fn on_with_cleanup<F, C>(
+        &self,
+        mut listener: F,
+        cleanup: C,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+        C: FnOnce() + Send,
+    {
+        let mut cleanup = Cleanup(Some(cleanup));
+
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            listener(event, flow);
+            if flow.done() {
+                cleanup.run();
+            }
+        })
+    }
+
+    /// Listen events, awaiting future from `cleanup` once listener is finished
+    ///
+    /// `cleanup` is not called if returned future is dropped before listener is finished, as dropping cannot await.
+    /// Finish listener using [`ControlFlow::set_done`] or [`EventSource::close`] to run it.
+    pub async fn on_with_async_cleanup<F, C, Fut>(&self, listener: F, cleanup: C)
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+        C: FnOnce() -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        self.on(listener).await;
+        cleanup().await;
+    }
+
+    /// Listen events, waking its task once `budget` events are arrived since last poll
+    ///
+    /// Long run of synchronous emissions wakes the task periodically, so the executor can schedule it in between.
+    ///
+    /// # Panics
+    /// Panics if `budget` is zero
+    pub async fn on_budgeted<F>(&self, budget: usize, mut listener: F)
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        assert!(budget > 0, "budget must be greater than zero");
+
+        let count = AtomicUsize::new(0);
+        let mut future = pin!(self.on(|event, flow| {
+            listener(event, flow);
+
+            if count.fetch_add(1, Ordering::Relaxed) + 1 == budget {
+                flow.request_wake();
+            }
+        }));
+
+        poll_fn(|cx| {
+            count.store(0, Ordering::Relaxed);
+            future.as_mut().poll(cx)
+        })
+        .await;
+    }
+
+    /// Listen events until `token` is cancelled
+    ///
+    /// Listener is finished without being called once `token` is cancelled, and its task is woken by [`CancelToken::cancel`].
+    pub async fn on_cancellable<F>(&self, token: &CancelToken, mut listener: F)
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let mut cancelled = pin!(token.source.on(|_, _| {}));
+        let mut future = pin!(self.on(|event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if token.is_cancelled() {
+                flow.set_done();
+            } else {
+                listener(event, flow);
+            }
+        }));
+
+        poll_fn(|cx| {
+            if cancelled.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(());
+            }
+
+            future.as_mut().poll(cx)
+        })
+        .await;
+    }
+
+    /// Listen events until no event arrives before idle delay future elapses
+    ///
+    /// New delay future is created with `idle` on start and after every event.
+    pub async fn on_idle_timeout<D, Fut, F>(&self, mut idle: D, mut listener: F)
+    where
+        D: FnMut() -> Fut,
+        Fut: Future<Output = ()>,
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let reset = AtomicBool::new(false);
+        let mut future = pin!(self.on(|event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            listener(event, flow);
+            reset.store(true, Ordering::Relaxed);
+            flow.request_wake();
+        }));
+
+        let mut delay = pin!(idle());
+        poll_fn(|cx| {
+            if future.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(());
+            }
+
+            if reset.swap(false, Ordering::Relaxed) {
+                delay.set(idle());
+            }
+
+            delay.as_mut().poll(cx)
+        })
+        .await;
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Listen events with every listeners until one of them returns [`Option::Some`]
+    ///
+    /// Each listener is registered separately. Remaining listeners are removed once one of them is done.
+    /// Returns [`Option::None`] immediately if `listeners` is empty, or once source is closed before any listener returns.
+    pub async fn race<'a, R: Send>(&self, listeners: Vec<RaceListener<'a, T, R>>) -> Option<R> {
+        if listeners.is_empty() {
+            return None;
+        }
+
+        let out = Mutex::new(None);
+
+        let mut futures = listeners
+            .into_iter()
+            .map(|mut listener| {
+                let out = &out;
+
+                Box::pin(self.on(move |event, flow| {
+                    if flow.done() {
+                        return;
+                    }
+
+                    let mut out = out.lock();
+                    if out.is_some() {
+                        return;
+                    }
+
+                    if let output @ Some(_) = listener(event, flow) {
+                        *out = output;
+                        flow.set_done();
+                    }
+                }))
+            })
+            .collect::<Vec<_>>();
+
+        poll_fn(|cx| {
+            for future in &mut futures {
+                if future.as_mut().poll(cx).is_ready() {
+                    return Poll::Ready(());
+                }
+            }
+
+            Poll::Pending
+        })
+        .await;
+
+        drop(futures);
+        out.into_inner()
+    }
+
+    #[cfg(feature = "std")]
+    #[allow(clippy::type_complexity)]
+    /// Listen events, sending outputs of `f` to returned [`Receiver`]
+    ///
+    /// Listener is finished once [`Receiver`] is dropped.
+    pub fn on_results<F, R>(
+        &self,
+        mut f: F,
+    ) -> (
+        EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>,
+        Receiver<R>,
+    )
+    where
+        F: FnMut(T::Of<'_>) -> Option<R> + Send,
+        R: Send,
+    {
+        let (sender, receiver) = mpsc::channel();
+
+        let future = self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if let Some(output) = f(event) {
+                if sender.send(output).is_err() {
+                    flow.set_done();
+                }
+            }
+        });
+
+        (future, receiver)
+    }
+
+    #[cfg(feature = "std")]
+    /// Listen events, asserting listener is only called from current thread in debug build
+    pub fn on_pinned_thread<F>(
+        &self,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let thread = std::thread::current().id();
+
+        self.on(move |event, flow| {
+            debug_assert_eq!(
+                std::thread::current().id(),
+                thread,
+                "listener is called from other thread"
+            );
+
+            listener(event, flow);
+        })
+    }
+
+    #[cfg(feature = "alloc")]
+    #[allow(clippy::type_complexity)]
+    /// Listen events, counting listener calls in returned counter
+    pub fn on_counted<F>(
+        &self,
+        mut listener: F,
+    ) -> (
+        EventFnFuture<'_, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send, T>,
+        Arc<AtomicUsize>,
+    )
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let count = Arc::new(AtomicUsize::new(0));
+
+        let future = self.on({
+            let count = count.clone();
+
+            move |event, flow| {
+                count.fetch_add(1, Ordering::Relaxed);
+                listener(event, flow);
+            }
+        });
+
+        (future, count)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<O: Send> EventSource<crate::__private::ForLt!(O)> {
+    /// Wait until every source emits an event, returning first event of each source in order
+    ///
+    /// Returns [`Option::None`] if any of sources is closed before emitting.
+    pub async fn join_all(sources: &[&Self]) -> Option<Vec<O>> {
+        let outs = sources.iter().map(|_| Mutex::new(None)).collect::<Vec<_>>();
+
+        let mut futures = sources
+            .iter()
+            .zip(&outs)
+            .map(|(source, out)| {
+                Some(Box::pin(source.on(move |event, flow| {
+                    if flow.done() {
+                        return;
+                    }
+
+                    *out.lock() = Some(event);
+                    flow.set_done();
+                })))
+            })
+            .collect::<Vec<_>>();
+
+        poll_fn(|cx| {
+            let mut pending = false;
+            for (slot, out) in futures.iter_mut().zip(&outs) {
+                if let Some(future) = slot {
+                    if future.as_mut().poll(cx).is_ready() {
+                        // Source is closed without emitting
+                        if out.lock().is_none() {
+                            return Poll::Ready(());
+                        }
+
+                        *slot = None;
+                    } else {
+                        pending = true;
+                    }
+                }
+            }
+
+            if pending {
+                Poll::Pending
+            } else {
+                Poll::Ready(())
+            }
+        })
+        .await;
+
+        drop(futures);
+        outs.into_iter().map(Mutex::into_inner).collect()
+    }
+
+    /// Listen events of every sources in order of key from `key_of`
+    ///
+    /// Up to `max_skew` events are buffered to reorder events arrived out of order across sources, and smallest one is delivered once buffer exceeds it.
+    /// Events with equal keys are delivered in arrival order. Events arrived after a bigger key is delivered are delivered as soon as possible.
+    /// Remaining events are delivered in order once every source is closed.
+    pub async fn merge_ordered<K, G, F>(sources: &[&Self], key_of: G, max_skew: usize, listener: F)
+    where
+        K: Ord + Send,
+        G: Fn(&O) -> K + Sync,
+        F: FnMut(O, &mut ControlFlow) + Send,
+    {
+        let state = Mutex::new(MergeState {
+            events: BTreeMap::new(),
+            seq: 0,
+            listener,
+            done: false,
+        });
+
+        let mut futures = sources
+            .iter()
+            .map(|source| {
+                Some(Box::pin(source.on(|event, flow| {
+                    if flow.done() {
+                        return;
+                    }
+
+                    let mut state = state.lock();
+                    if !state.done {
+                        let seq = state.seq;
+                        state.seq += 1;
+                        state.events.insert((key_of(&event), seq), event);
+
+                        state.deliver(max_skew, flow);
+                    }
+
+                    if state.done {
+                        flow.set_done();
+                    }
+                })))
+            })
+            .collect::<Vec<_>>();
+
+        poll_fn(|cx| {
+            let mut pending = false;
+            for slot in &mut futures {
+                if let Some(future) = slot {
+                    if future.as_mut().poll(cx).is_ready() {
+                        *slot = None;
+                    } else {
+                        pending = true;
+                    }
+                }
+            }
+
+            if pending && !state.lock().done {
+                Poll::Pending
+            } else {
+                Poll::Ready(())
+            }
+        })
+        .await;
+
+        drop(futures);
+        state.into_inner().deliver(0, &mut ControlFlow::new(0));
+    }
+}
+
+#[cfg(feature = "alloc")]
+struct MergeState<K, O, F> {
+    events: BTreeMap<(K, u64), O>,
+    seq: u64,
+    listener: F,
+    done: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord, O, F: FnMut(O, &mut ControlFlow)> MergeState<K, O, F> {
+    /// Deliver smallest events until `max` events are left or listener is finished
+    fn deliver(&mut self, max: usize, flow: &mut ControlFlow) {
+        while !self.done && self.events.len() > max {
+            let (_, event) = match self.events.pop_first() {
+                Some(entry) => entry,
+                None => return,
+            };
+
+            (self.listener)(event, flow);
+            self.done = flow.done();
+        }
+    }
+}
+
+/// Call inner closure on drop if it was not called
+struct Cleanup<C: FnOnce()>(Option<C>);
+
+impl<C: FnOnce()> Cleanup<C> {
+    fn run(&mut self) {
+        if let Some(cleanup) = self.0.take() {
+            cleanup();
+        }
+    }
+}
+
+impl<C: FnOnce()> Drop for Cleanup<C> {
+    fn drop(&mut self) {
+        self.run();
+    }
+}
+
+type AnyEvent = crate::__private::ForLt!(&'_ dyn Any);
+
+impl EventSource<AnyEvent> {
+    /// Listen events which are type of `U`
+    ///
+    /// Listener is only called for events downcasted to `U` successfully.
+    pub fn on_as<U: 'static, F>(
+        &self,
+        mut listener: F,
+    ) -> EventFnFuture<'_, impl FnMut(&dyn Any, &mut ControlFlow) + Send, AnyEvent>
+    where
+        F: FnMut(&U, &mut ControlFlow) + Send,
+    {
+        self.on(move |event: &dyn Any, flow| {
+            if let Some(event) = event.downcast_ref::<U>() {
+                listener(event, flow);
+            }
+        })
+    }
+}
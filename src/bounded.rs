@@ -0,0 +1,177 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    fmt::{self, Debug},
+    future::{poll_fn, Future},
+    mem,
+    ops::Deref,
+    pin::pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::Poll,
+};
+
+use alloc::collections::VecDeque;
+use parking_lot::Mutex;
+
+use crate::{__private::ForLt, buffered::deliver, waker::WakerSet, ControlFlow, EventSource};
+
+#[derive(Debug)]
+/// Number of full bounded listeners and emitters waiting for them
+struct Backpressure {
+    full: usize,
+    waiters: WakerSet,
+}
+
+/// Release backpressure of bounded listener if it is full, even if its future is dropped
+struct FullGuard<'a> {
+    backpressure: &'a Mutex<Backpressure>,
+    full: &'a AtomicBool,
+}
+
+impl FullGuard<'_> {
+    fn release(&self) {
+        if !self.full.swap(false, Ordering::Relaxed) {
+            return;
+        }
+
+        let waiters = {
+            let mut backpressure = self.backpressure.lock();
+            backpressure.full -= 1;
+            if backpressure.full > 0 {
+                return;
+            }
+
+            mem::take(&mut backpressure.waiters)
+        };
+
+        // Woken after lock is released
+        drop(waiters);
+    }
+}
+
+impl Drop for FullGuard<'_> {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+/// [`struct@EventSource`] whose emitters can wait for slow listeners
+///
+/// [`BoundedEventSource::emit_async`] waits until no listener of [`BoundedEventSource::on_bounded`] is full.
+/// Events emitted directly on inner [`struct@EventSource`] do not wait.
+pub struct BoundedEventSource<O> {
+    source: EventSource<ForLt!(O)>,
+    backpressure: Mutex<Backpressure>,
+}
+
+impl<O> Debug for BoundedEventSource<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoundedEventSource")
+            .field("source", &self.source)
+            .field("full", &self.backpressure.lock().full)
+            .finish()
+    }
+}
+
+impl<O> Default for BoundedEventSource<O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<O> BoundedEventSource<O> {
+    /// Create new [`BoundedEventSource`]
+    pub const fn new() -> Self {
+        Self {
+            source: EventSource::new(),
+            backpressure: Mutex::new(Backpressure {
+                full: 0,
+                waiters: WakerSet::new(),
+            }),
+        }
+    }
+}
+
+impl<O: Clone + Send> BoundedEventSource<O> {
+    /// Emit event to every listeners like [`EventSource::emit_owned`], once no listener of [`BoundedEventSource::on_bounded`] is full
+    ///
+    /// Returns number of listeners notified.
+    pub async fn emit_async(&self, event: O) -> usize {
+        poll_fn(|cx| {
+            let mut backpressure = self.backpressure.lock();
+            if backpressure.full > 0 {
+                backpressure.waiters.insert(cx.waker().clone());
+                return Poll::Pending;
+            }
+
+            Poll::Ready(())
+        })
+        .await;
+
+        self.source.emit_owned(event)
+    }
+}
+
+impl<O: Send> BoundedEventSource<O> {
+    /// Listen events in task of returned future instead of emitter, pausing [`BoundedEventSource::emit_async`] while `capacity` events are buffered
+    ///
+    /// Events are never dropped. Events buffered when source is closed are delivered before returned future resolves.
+    /// Buffer can exceed `capacity` by emissions not using [`BoundedEventSource::emit_async`] or started before it became full.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero
+    pub async fn on_bounded<F>(&self, capacity: usize, mut listener: F)
+    where
+        F: FnMut(O, &mut ControlFlow),
+    {
+        assert!(capacity > 0, "capacity must be greater than zero");
+
+        let buffer = Mutex::new(VecDeque::with_capacity(capacity));
+        let full = AtomicBool::new(false);
+        // Declared before listener, so it is released after listener is removed
+        let guard = FullGuard {
+            backpressure: &self.backpressure,
+            full: &full,
+        };
+
+        let mut future = pin!(self.source.on(|event, flow| {
+            let mut buffer = buffer.lock();
+            buffer.push_back(event);
+            flow.request_wake();
+
+            if buffer.len() >= capacity && !full.swap(true, Ordering::Relaxed) {
+                self.backpressure.lock().full += 1;
+            }
+        }));
+
+        poll_fn(|cx| {
+            if future.as_mut().poll(cx).is_ready() {
+                let _ = deliver(|| buffer.lock().pop_front(), &mut listener);
+                return Poll::Ready(());
+            }
+
+            let res = deliver(|| buffer.lock().pop_front(), &mut listener);
+
+            // Checked while holding buffer lock, so emitter cannot fill it in between
+            let buffer = buffer.lock();
+            if buffer.len() < capacity {
+                guard.release();
+            }
+
+            res
+        })
+        .await;
+    }
+}
+
+impl<O> Deref for BoundedEventSource<O> {
+    type Target = EventSource<ForLt!(O)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.source
+    }
+}
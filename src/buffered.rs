@@ -0,0 +1,417 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    future::{poll_fn, Future},
+    pin::pin,
+    task::Poll,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::collections::{BTreeMap, VecDeque};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::mem;
+use parking_lot::Mutex;
+
+use crate::{ControlFlow, EventSource};
+
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Which event to drop when buffer of lossy listener is full
+pub enum DropPolicy {
+    /// Drop oldest buffered event to make room for new event
+    Oldest,
+
+    /// Drop new event
+    Newest,
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+struct LossyBuffer<O> {
+    events: VecDeque<O>,
+    dropped: usize,
+}
+
+#[derive(Debug, Default)]
+/// Slot holding latest event stored by [`EventSource::on_into_slot`]
+///
+/// Works like [`Cell`](core::cell::Cell), but can be shared with listeners called from other threads.
+pub struct EventSlot<O>(Mutex<Option<O>>);
+
+impl<O> EventSlot<O> {
+    /// Create new empty [`EventSlot`]
+    pub const fn new() -> Self {
+        Self(Mutex::new(None))
+    }
+
+    /// Take stored event, leaving slot empty
+    pub fn take(&self) -> Option<O> {
+        self.0.lock().take()
+    }
+
+    /// Clone stored event
+    pub fn get(&self) -> Option<O>
+    where
+        O: Clone,
+    {
+        self.0.lock().clone()
+    }
+
+    /// Consume slot, returning stored event
+    pub fn into_inner(self) -> Option<O> {
+        self.0.into_inner()
+    }
+}
+
+impl<O: Send> EventSource<crate::__private::ForLt!(O)> {
+    /// Listen events in task of returned future instead of emitter
+    ///
+    /// Events are buffered up to `capacity` until the task is polled. If buffer is full, an event is dropped according to `policy`.
+    /// Events buffered when source is closed are delivered before returned future resolves.
+    /// Resolves with number of dropped events once listener is finished.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero
+    #[cfg(feature = "alloc")]
+    pub async fn on_lossy<F>(&self, capacity: usize, policy: DropPolicy, listener: F) -> usize
+    where
+        F: FnMut(O, &mut ControlFlow),
+    {
+        self.on_lossy_with_dropped(capacity, policy, |_| {}, listener)
+            .await
+    }
+
+    /// Listen events like [`EventSource::on_lossy`], calling `on_dropped` with every dropped events
+    ///
+    /// `on_dropped` is called while emitting.
+    ///
+    /// # Panics
+    /// Panics if `capacity` is zero
+    #[cfg(feature = "alloc")]
+    pub async fn on_lossy_with_dropped<D, F>(
+        &self,
+        capacity: usize,
+        policy: DropPolicy,
+        mut on_dropped: D,
+        mut listener: F,
+    ) -> usize
+    where
+        D: FnMut(&O) + Send,
+        F: FnMut(O, &mut ControlFlow),
+    {
+        assert!(capacity > 0, "capacity must be greater than zero");
+
+        let buffer = Mutex::new(LossyBuffer {
+            events: VecDeque::with_capacity(capacity),
+            dropped: 0,
+        });
+
+        {
+            let mut future = pin!(self.on(|event, flow| {
+                let mut buffer = buffer.lock();
+
+                if buffer.events.len() == capacity {
+                    buffer.dropped += 1;
+
+                    match policy {
+                        DropPolicy::Oldest => {
+                            if let Some(dropped) = buffer.events.pop_front() {
+                                on_dropped(&dropped);
+                            }
+                        }
+
+                        DropPolicy::Newest => {
+                            on_dropped(&event);
+                            return;
+                        }
+                    }
+                }
+
+                buffer.events.push_back(event);
+                flow.request_wake();
+            }));
+
+            poll_fn(|cx| {
+                if future.as_mut().poll(cx).is_ready() {
+                    let _ = deliver(|| buffer.lock().events.pop_front(), &mut listener);
+                    return Poll::Ready(());
+                }
+
+                deliver(|| buffer.lock().events.pop_front(), &mut listener)
+            })
+            .await;
+        }
+
+        buffer.into_inner().dropped
+    }
+
+    /// Listen latest event in task of returned future instead of emitter
+    ///
+    /// Only the most recent event emitted since the task was last polled is delivered.
+    /// Event stored when source is closed is delivered before returned future resolves.
+    pub async fn on_latest<F>(&self, listener: F)
+    where
+        F: FnMut(O, &mut ControlFlow),
+    {
+        self.on_latest_with_dropped(|_| {}, listener).await;
+    }
+
+    /// Listen latest event like [`EventSource::on_latest`], calling `on_dropped` with every overwritten events
+    ///
+    /// `on_dropped` is called while emitting.
+    pub async fn on_latest_with_dropped<D, F>(&self, mut on_dropped: D, mut listener: F)
+    where
+        D: FnMut(&O) + Send,
+        F: FnMut(O, &mut ControlFlow),
+    {
+        let slot = Mutex::new(None);
+
+        let mut future = pin!(self.on(|event, flow| {
+            if let Some(dropped) = slot.lock().replace(event) {
+                on_dropped(&dropped);
+            }
+            flow.request_wake();
+        }));
+
+        poll_fn(|cx| {
+            if future.as_mut().poll(cx).is_ready() {
+                let _ = deliver(|| slot.lock().take(), &mut listener);
+                return Poll::Ready(());
+            }
+
+            deliver(|| slot.lock().take(), &mut listener)
+        })
+        .await;
+    }
+
+    /// Listen events, storing latest event in `slot`
+    pub async fn on_into_slot(&self, slot: &EventSlot<O>) {
+        self.on(|event, _| {
+            *slot.0.lock() = Some(event);
+        })
+        .await
+    }
+
+    /// Listen events, calling listener with difference from previous event computed by `diff`
+    ///
+    /// First event only becomes previous event, as it has no predecessor.
+    pub async fn on_diffed<D, G, F>(&self, mut diff: G, mut listener: F)
+    where
+        G: FnMut(&O, &O) -> D + Send,
+        F: FnMut(D, &mut ControlFlow) + Send,
+    {
+        let mut prev = None;
+
+        self.on(|event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if let Some(ref prev) = prev {
+                listener(diff(prev, &event), flow);
+            }
+            prev = Some(event);
+        })
+        .await;
+    }
+
+    /// Listen events, calling listener with `initial` first when returned future is polled
+    pub async fn on_with_initial<F>(&self, initial: O, mut listener: F)
+    where
+        F: FnMut(O, &mut ControlFlow) + Send,
+    {
+        let mut flow = ControlFlow::new(0);
+        listener(initial, &mut flow);
+        if flow.done() {
+            return;
+        }
+
+        self.on(listener).await;
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Listen events, calling listener with last `window` events including current one
+    ///
+    /// Window grows until it holds `window` events.
+    ///
+    /// # Panics
+    /// Panics if `window` is zero
+    pub async fn on_windowed<F>(&self, window: usize, mut listener: F)
+    where
+        F: FnMut(&[O], &mut ControlFlow) + Send,
+    {
+        assert!(window > 0, "window must be greater than zero");
+
+        let mut events = VecDeque::with_capacity(window);
+
+        self.on(|event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if events.len() >= window {
+                events.pop_front();
+            }
+            events.push_back(event);
+
+            listener(events.make_contiguous(), flow);
+        })
+        .await;
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Listen events, calling listener with chunks of `size` events
+    ///
+    /// If `flush` is true, remaining events are delivered as partial chunk once listener is finished or source is closed.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero
+    pub async fn on_chunked<F>(&self, size: usize, flush: bool, mut listener: F)
+    where
+        F: FnMut(Vec<O>, &mut ControlFlow) + Send,
+    {
+        assert!(size > 0, "size must be greater than zero");
+
+        let mut chunk = Vec::with_capacity(size);
+
+        self.on(|event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            chunk.push(event);
+            if chunk.len() >= size {
+                listener(mem::replace(&mut chunk, Vec::with_capacity(size)), flow);
+            }
+        })
+        .await;
+
+        if flush && !chunk.is_empty() {
+            listener(chunk, &mut ControlFlow::new(0));
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Listen events in order of sequence number from `seq_of`
+    ///
+    /// Sequence starts from sequence number of first event. Events arrived ahead of next sequence number are held back until the gap is filled.
+    /// Once `buffer_size` events are held back, gap is skipped and held back events are delivered up to next gap.
+    /// Events behind next sequence number are dropped.
+    pub async fn on_reordered<S, F>(&self, buffer_size: usize, seq_of: S, mut listener: F)
+    where
+        S: Fn(&O) -> u64 + Send,
+        F: FnMut(O, &mut ControlFlow) + Send,
+    {
+        let mut next = None;
+        let mut held = BTreeMap::new();
+
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            let seq = seq_of(&event);
+            let expected = *next.get_or_insert(seq);
+            if seq < expected {
+                return;
+            }
+            held.insert(seq, event);
+
+            let mut expected = match held.first_key_value() {
+                Some((&first, _)) if held.len() > buffer_size => first,
+                _ => expected,
+            };
+            while let Some(event) = held.remove(&expected) {
+                expected += 1;
+                listener(event, flow);
+                if flow.done() {
+                    return;
+                }
+            }
+            next = Some(expected);
+        })
+        .await;
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Listen events, grouping them by key from `key_of`
+    ///
+    /// Once listener is finished by `key_of` using [`ControlFlow::set_done`] or source is closed, `handler` is called for each group in key order, with its events in emitted order.
+    /// Event passed to `key_of` finishing listener is also grouped.
+    pub async fn on_grouped<K, G, F>(&self, mut key_of: G, mut handler: F)
+    where
+        K: Ord + Send,
+        G: FnMut(&O, &mut ControlFlow) -> K + Send,
+        F: FnMut(&K, &[O]),
+    {
+        let mut groups = BTreeMap::<K, Vec<O>>::new();
+
+        {
+            let groups = &mut groups;
+            self.on(move |event, flow| {
+                if flow.done() {
+                    return;
+                }
+
+                groups.entry(key_of(&event, flow)).or_default().push(event);
+            })
+            .await;
+        }
+
+        for (key, events) in &groups {
+            handler(key, events);
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<U: Clone + Send> EventSource<crate::__private::ForLt!(&'_ mut U)> {
+    /// Listen snapshots of events in task of returned future instead of emitter
+    ///
+    /// Value is cloned on every emission and delivered to listener in order once the task is polled.
+    /// Listener cannot mutate the value, as it only observes its snapshot.
+    /// Snapshots buffered when source is closed are delivered before returned future resolves.
+    pub async fn on_snapshot<F>(&self, mut listener: F)
+    where
+        F: FnMut(U, &mut ControlFlow),
+    {
+        let buffer = Mutex::new(VecDeque::new());
+
+        let mut future = pin!(self.on(|event: &mut U, flow| {
+            buffer.lock().push_back(event.clone());
+            flow.request_wake();
+        }));
+
+        poll_fn(|cx| {
+            if future.as_mut().poll(cx).is_ready() {
+                let _ = deliver(|| buffer.lock().pop_front(), &mut listener);
+                return Poll::Ready(());
+            }
+
+            deliver(|| buffer.lock().pop_front(), &mut listener)
+        })
+        .await;
+    }
+}
+
+/// Deliver events from `next` to listener until there is no event or listener is finished
+pub(crate) fn deliver<O>(
+    mut next: impl FnMut() -> Option<O>,
+    listener: &mut impl FnMut(O, &mut ControlFlow),
+) -> Poll<()> {
+    while let Some(event) = next() {
+        let mut flow = ControlFlow::new(0);
+        listener(event, &mut flow);
+        if flow.done() {
+            return Poll::Ready(());
+        }
+    }
+
+    Poll::Pending
+}
@@ -0,0 +1,97 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    future::Future,
+    pin::Pin,
+    sync::atomic::{AtomicBool, Ordering},
+    task::{Context, Poll, Waker},
+};
+
+use alloc::sync::Arc;
+use higher_kinded_types::ForLifetime;
+use parking_lot::Mutex;
+
+use crate::{waker::WakerSet, ControlFlow, EventFnFuture, EventSource};
+
+#[derive(Debug, Default)]
+struct DoneState {
+    done: AtomicBool,
+    waker: Mutex<Option<Waker>>,
+}
+
+/// Signal completing [`DoneFuture`] once listener is finished or dropped
+#[derive(Debug)]
+pub(crate) struct DoneSignal(Arc<DoneState>);
+
+impl DoneSignal {
+    /// Complete [`DoneFuture`], inserting its waker to `wakers` to wake it after lock is released
+    pub fn finish(&self, wakers: &mut WakerSet) {
+        if let Some(waker) = self.complete() {
+            wakers.insert(waker);
+        }
+    }
+
+    fn complete(&self) -> Option<Waker> {
+        if self.0.done.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+
+        self.0.waker.lock().take()
+    }
+}
+
+impl Drop for DoneSignal {
+    // Owner of listener drops it after lock is released
+    fn drop(&mut self) {
+        if let Some(waker) = self.complete() {
+            waker.wake();
+        }
+    }
+}
+
+#[derive(Debug)]
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+/// Future completed once listener is finished or removed
+///
+/// Created with [`EventSource::on_with_done_signal`].
+pub struct DoneFuture(Arc<DoneState>);
+
+impl Future for DoneFuture {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        if self.0.done.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+
+        *self.0.waker.lock() = Some(cx.waker().clone());
+
+        // Check again in case listener finished before waker is stored
+        if self.0.done.load(Ordering::Acquire) {
+            return Poll::Ready(());
+        }
+
+        Poll::Pending
+    }
+}
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Listen events, returning [`DoneFuture`] completed once listener is finished or its future is dropped
+    ///
+    /// Listener finished by itself, [`EventSource::close`] or [`EventSource::cancel`] is signalled immediately.
+    pub fn on_with_done_signal<F>(&self, listener: F) -> (EventFnFuture<'_, F, T>, DoneFuture)
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let state = Arc::new(DoneState::default());
+
+        (
+            EventFnFuture::new(self, listener).with_done_signal(DoneSignal(state.clone())),
+            DoneFuture(state),
+        )
+    }
+}
@@ -0,0 +1,262 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    fmt::{self, Debug, Display},
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use higher_kinded_types::ForLifetime;
+use parking_lot::Mutex;
+use unique::Unique;
+
+use crate::{future::ListenerItem, sealed::Sealed, waker::WakerSet, ControlFlow};
+
+#[macro_export]
+/// Higher kinded type helper for [`StaticEventSource`]
+macro_rules! StaticEventSource {
+    ($n: expr; $($ty: tt)*) => {
+        $crate::StaticEventSource<$crate::__private::ForLt!($($ty)*), $n>
+    };
+}
+
+enum Slot<T: ForLifetime> {
+    Vacant,
+    Reserved,
+    Occupied(ListenerItem<T>),
+}
+
+impl<T: ForLifetime> Debug for Slot<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Vacant => f.write_str("Vacant"),
+            Self::Reserved => f.write_str("Reserved"),
+            Self::Occupied(item) => f.debug_tuple("Occupied").field(&item.snapshot()).finish(),
+        }
+    }
+}
+
+/// Event source holding at most `N` listeners inline, without allocation
+pub struct StaticEventSource<T: ForLifetime, const N: usize> {
+    slots: Mutex<[Slot<T>; N]>,
+}
+
+impl<T: ForLifetime, const N: usize> Debug for StaticEventSource<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StaticEventSource")
+            .field("slots", &self.slots)
+            .finish()
+    }
+}
+
+impl<T: ForLifetime, const N: usize> Default for StaticEventSource<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ForLifetime, const N: usize> StaticEventSource<T, N> {
+    /// Create new [`StaticEventSource`]
+    pub const fn new() -> Self {
+        Self {
+            slots: Mutex::new([const { Slot::Vacant }; N]),
+        }
+    }
+
+    /// Create [`StaticEventEmitter`] for this [`StaticEventSource`]
+    pub fn with_emitter(&self, emit_fn: impl FnOnce(StaticEventEmitter<T>)) {
+        // Declared before the lock guard, so wakers are woken after lock is released
+        let mut wakers = WakerSet::new();
+
+        {
+            let mut slots = self.slots.lock();
+            let remaining = slots
+                .iter()
+                .filter(|slot| matches!(slot, Slot::Occupied(_)))
+                .count();
+
+            emit_fn(StaticEventEmitter {
+                slots: &mut *slots,
+                index: 0,
+                remaining,
+                wakers: &mut wakers,
+            });
+        }
+
+        #[cfg(not(feature = "alloc"))]
+        let mut overflowed = wakers.overflowed();
+        drop(wakers);
+
+        // Each batch of deferred wakers is woken after lock is released
+        #[cfg(not(feature = "alloc"))]
+        while overflowed {
+            let mut wakers = WakerSet::new();
+            for slot in self.slots.lock().iter_mut() {
+                if let Slot::Occupied(item) = slot {
+                    if !item.take_deferred(&mut wakers) {
+                        break;
+                    }
+                }
+            }
+
+            overflowed = wakers.overflowed();
+        }
+    }
+
+    /// Listen events
+    ///
+    /// Slot is reserved immediately, returns [`CapacityError`] if every slot is in use.
+    pub fn on<F>(&self, listener: F) -> Result<StaticEventFnFuture<'_, F, T>, CapacityError>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let mut slots = self.slots.lock();
+        let index = slots
+            .iter()
+            .position(|slot| matches!(slot, Slot::Vacant))
+            .ok_or(CapacityError)?;
+        slots[index] = Slot::Reserved;
+
+        Ok(StaticEventFnFuture {
+            slots: &self.slots,
+            index,
+            listener: Sealed::new(listener),
+        })
+    }
+
+    /// Listen event until listener returns [`Option::Some`]
+    ///
+    /// See [`crate::EventSource::once`]
+    pub async fn once<F, R>(&self, mut listener: F) -> Result<Option<R>, CapacityError>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) -> Option<R> + Send,
+        R: Send,
+    {
+        let mut out = None;
+
+        self.on(|event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if let output @ Some(_) = listener(event, flow) {
+                out = output;
+                flow.set_done();
+            }
+        })?
+        .await;
+
+        Ok(out)
+    }
+}
+
+pin_project_lite::pin_project!(
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    /// Future created with [`StaticEventSource::on`]
+    pub struct StaticEventFnFuture<'a, F, T: ForLifetime> {
+        slots: &'a Mutex<[Slot<T>]>,
+        index: usize,
+
+        #[pin]
+        listener: Sealed<F>,
+    }
+
+    impl<F, T: ForLifetime> PinnedDrop for StaticEventFnFuture<'_, F, T> {
+        fn drop(this: Pin<&mut Self>) {
+            let project = this.project();
+            project.slots.lock()[*project.index] = Slot::Vacant;
+        }
+    }
+);
+
+impl<T: ForLifetime, F: FnMut(T::Of<'_>, &mut ControlFlow) + Send> Future
+    for StaticEventFnFuture<'_, F, T>
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        let mut slots = this.slots.lock();
+        let slot = &mut slots[*this.index];
+        let item = match slot {
+            Slot::Occupied(item) => item,
+
+            _ => {
+                *slot = Slot::Occupied(ListenerItem::new(
+                    Unique::new(this.listener.get_ptr_mut().as_ptr() as _).unwrap(),
+                ));
+
+                match slot {
+                    Slot::Occupied(item) => item,
+                    _ => unreachable!(),
+                }
+            }
+        };
+
+        if item.done() {
+            return Poll::Ready(());
+        }
+
+        item.update_waker(cx.waker());
+
+        Poll::Pending
+    }
+}
+
+/// Struct for emitting values for each listeners of [`StaticEventSource`]
+#[derive(Debug)]
+pub struct StaticEventEmitter<'a, T: ForLifetime> {
+    slots: &'a mut [Slot<T>],
+    index: usize,
+    /// Number of occupied slots from `index`
+    remaining: usize,
+    wakers: &'a mut WakerSet,
+}
+
+impl<T: ForLifetime> StaticEventEmitter<'_, T> {
+    /// Emit event to next listener
+    pub fn emit_next(&mut self, event: T::Of<'_>) -> Option<()> {
+        let index = self.index
+            + self.slots[self.index..]
+                .iter()
+                .position(|slot| matches!(slot, Slot::Occupied(_)))?;
+        self.remaining -= 1;
+
+        let item = match &mut self.slots[index] {
+            Slot::Occupied(item) => item,
+            _ => unreachable!(),
+        };
+
+        let mut flow = ControlFlow::new(self.remaining);
+        // SAFETY: Listener closure pointer is valid while slot is occupied
+        unsafe { item.poll(event, &mut flow, self.wakers) };
+
+        if !flow.propagation() {
+            return None;
+        }
+
+        self.index = index + 1;
+
+        Some(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Error returned when every listener slot of [`StaticEventSource`] is in use
+pub struct CapacityError;
+
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("listener capacity exceeded")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CapacityError {}
@@ -8,14 +8,26 @@ use core::{
     future::Future,
     mem,
     pin::Pin,
+    sync::atomic::Ordering,
     task::{Context, Poll, Waker},
 };
 
+#[cfg(feature = "alloc")]
+use crate::{done::DoneSignal, id::HandleState};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, sync::Arc};
+#[cfg(feature = "alloc")]
+use core::{
+    any::Any,
+    fmt::{self, Debug},
+};
 use higher_kinded_types::ForLifetime;
 use sync_wrapper::SyncWrapper;
 use unique::Unique;
 
-use crate::{sealed::Sealed, types::Node, EventSource};
+use crate::{
+    id::ListenerId, key::ListenerKey, sealed::Sealed, types::Node, waker::WakerSet, EventSource,
+};
 
 pin_project_lite::pin_project!(
     #[derive(Debug)]
@@ -29,17 +41,19 @@ pin_project_lite::pin_project!(
 
         #[pin]
         node: Node<T>,
+
+        options: ListenerOptions,
     }
 
     impl<F, T: ForLifetime> PinnedDrop for EventFnFuture<'_, F, T> {
         fn drop(this: Pin<&mut Self>) {
             let project = this.project();
-            let node = match project.node.initialized_mut() {
-                Some(initialized) => initialized,
-                None => return,
-            };
+            drop_node(project.source, project.node);
 
-            let _ = node.reset(&mut project.source.list.lock());
+            #[cfg(feature = "alloc")]
+            if let Some(ref state) = project.options.handle {
+                state.end();
+            }
         }
     }
 );
@@ -50,8 +64,37 @@ impl<'a, T: ForLifetime, F> EventFnFuture<'a, F, T> {
             source,
             listener: Sealed::new(listener),
             node: pin_list::Node::new(),
+            options: ListenerOptions::new(),
         }
     }
+
+    pub(super) const fn with_id(mut self, id: ListenerId) -> Self {
+        self.options.id = Some(id);
+        self
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(super) fn with_key(mut self, key: ListenerKey) -> Self {
+        self.options.key = Some(key);
+        self
+    }
+
+    pub(super) fn with_waker(mut self, waker: Waker) -> Self {
+        self.options.waker = Some(waker);
+        self
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(super) fn with_done_signal(mut self, signal: DoneSignal) -> Self {
+        self.options.done = Some(signal);
+        self
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(super) fn with_handle(mut self, state: Arc<HandleState>) -> Self {
+        self.options.handle = Some(state);
+        self
+    }
 }
 
 impl<'a, T: ForLifetime, F: FnMut(T::Of<'_>, &mut ControlFlow) + Send> Future
@@ -60,58 +103,241 @@ impl<'a, T: ForLifetime, F: FnMut(T::Of<'_>, &mut ControlFlow) + Send> Future
     type Output = ();
 
     fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let mut this = self.project();
-
-        let mut list = this.source.list.lock();
-        let node = {
-            let initialized = match this.node.as_mut().initialized_mut() {
-                Some(initialized) => initialized,
-                None => list.push_back(
-                    this.node,
-                    ListenerItem::new(
-                        Unique::new(this.listener.get_ptr_mut().as_ptr() as _).unwrap(),
-                    ),
-                    (),
-                ),
-            };
-
-            initialized.protected_mut(&mut list).unwrap()
+        let this = self.project();
+
+        poll_node(this.source, this.listener, this.node, this.options, cx)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<'a, T: ForLifetime, F: FnMut(T::Of<'_>, &mut ControlFlow) + Send> futures_core::FusedFuture
+    for EventFnFuture<'a, F, T>
+{
+    fn is_terminated(&self) -> bool {
+        self.options.resolved
+    }
+}
+
+/// Register listener node if it is not registered and update its waker
+pub(crate) fn poll_node<T: ForLifetime, F: FnMut(T::Of<'_>, &mut ControlFlow) + Send>(
+    source: &EventSource<T>,
+    listener: Pin<&mut Sealed<F>>,
+    node: Pin<&mut Node<T>>,
+    options: &mut ListenerOptions,
+    cx: &mut Context<'_>,
+) -> Poll<()> {
+    if options.resolved {
+        return Poll::Ready(());
+    }
+
+    let poll = register_node(source, listener, node, options, cx);
+    if poll.is_ready() {
+        options.resolved = true;
+
+        // Signalled if listener resolved without being registered
+        #[cfg(feature = "alloc")]
+        drop(options.done.take());
+
+        #[cfg(feature = "alloc")]
+        if let Some(ref state) = options.handle {
+            state.end();
+        }
+    }
+
+    poll
+}
+
+fn register_node<T: ForLifetime, F: FnMut(T::Of<'_>, &mut ControlFlow) + Send>(
+    source: &EventSource<T>,
+    listener: Pin<&mut Sealed<F>>,
+    mut node: Pin<&mut Node<T>>,
+    options: &mut ListenerOptions,
+    cx: &mut Context<'_>,
+) -> Poll<()> {
+    if source.is_closed() {
+        return Poll::Ready(());
+    }
+
+    let mut list = source.lock_list();
+    let item = {
+        let initialized = match node.as_mut().initialized_mut() {
+            Some(initialized) => initialized,
+
+            // Lock is acquired after close stored closed state, so relaxed load is enough here
+            None if source.closed.load(Ordering::Relaxed) => return Poll::Ready(()),
+
+            // Cancel stores cancelled state before acquiring lock, so it is observed here while holding lock
+            #[cfg(feature = "alloc")]
+            None if options
+                .handle
+                .as_ref()
+                .is_some_and(|state| !state.register()) =>
+            {
+                return Poll::Ready(())
+            }
+
+            None => {
+                let mut item =
+                    ListenerItem::new(Unique::new(listener.get_ptr_mut().as_ptr() as _).unwrap());
+                item.key = options.key.take();
+                item.id = options.id;
+                #[cfg(feature = "alloc")]
+                {
+                    item.done_signal = options.done.take();
+                }
+                if let Some(waker) = options.waker.take() {
+                    item.waker = Some(waker);
+                    item.waker_fixed = true;
+                }
+
+                list.insert(node, item)
+            }
         };
 
-        if node.done {
-            return Poll::Ready(());
+        match initialized.protected_mut(&mut list.list) {
+            Some(item) => item,
+
+            // Listener is removed by EventSource::cancel
+            None => return Poll::Ready(()),
+        }
+    };
+
+    if item.done {
+        return Poll::Ready(());
+    }
+
+    item.wake_pending = false;
+    item.update_waker(cx.waker());
+
+    Poll::Pending
+}
+
+#[derive(Debug)]
+/// Options applied to listener when it is registered
+pub(crate) struct ListenerOptions {
+    pub key: Option<ListenerKey>,
+    pub id: Option<ListenerId>,
+    pub waker: Option<Waker>,
+
+    /// State shared with [`crate::ListenerHandle`], listener finishes without being registered if it is cancelled before first poll
+    #[cfg(feature = "alloc")]
+    pub handle: Option<Arc<HandleState>>,
+
+    #[cfg(feature = "alloc")]
+    pub done: Option<DoneSignal>,
+
+    /// Future returned [`Poll::Ready`] already
+    pub resolved: bool,
+}
+
+impl ListenerOptions {
+    pub const fn new() -> Self {
+        Self {
+            key: None,
+            id: None,
+            waker: None,
+
+            #[cfg(feature = "alloc")]
+            handle: None,
+
+            #[cfg(feature = "alloc")]
+            done: None,
+
+            resolved: false,
         }
+    }
+}
+
+/// Remove listener node if it is registered
+pub(crate) fn drop_node<T: ForLifetime>(source: &EventSource<T>, node: Pin<&mut Node<T>>) {
+    let node = match node.initialized_mut() {
+        Some(initialized) => initialized,
+        None => return,
+    };
+
+    // Item is dropped after lock is released
+    let item = source.lock_list().reset(node);
+    drop(item);
+}
 
-        node.update_waker(cx.waker());
+#[cfg(feature = "alloc")]
+/// Condition waking sleeping listener, see [`ControlFlow::sleep_until_condition`]
+struct SleepCondition(Box<dyn Fn() -> bool + Send>);
 
-        Poll::Pending
+#[cfg(feature = "alloc")]
+impl Debug for SleepCondition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SleepCondition").finish_non_exhaustive()
     }
 }
 
-type DynClosure<'closure, T> =
+pub(crate) type DynClosure<'closure, T> =
     dyn for<'a, 'b> FnMut(<T as ForLifetime>::Of<'a>, &'b mut ControlFlow) + Send + 'closure;
 
 #[derive(Debug)]
+/// Registered listener state
 pub struct ListenerItem<T: ForLifetime> {
     done: bool,
     waker: Option<Waker>,
+    waker_fixed: bool,
+    wake_pending: bool,
     closure_ptr: SyncWrapper<Unique<DynClosure<'static, T>>>,
+    key: Option<ListenerKey>,
+    id: Option<ListenerId>,
+
+    #[cfg(feature = "alloc")]
+    sleep: Option<SleepCondition>,
+
+    #[cfg(feature = "alloc")]
+    done_signal: Option<DoneSignal>,
+
+    /// Waker rejected by full [`WakerSet`], woken after lock is released
+    #[cfg(not(feature = "alloc"))]
+    deferred: Option<Waker>,
 }
 
 impl<T: ForLifetime> ListenerItem<T> {
-    fn new(closure: Unique<DynClosure<T>>) -> Self {
+    pub(crate) fn new(closure: Unique<DynClosure<T>>) -> Self {
         Self {
             done: false,
             waker: None,
+            waker_fixed: false,
+            wake_pending: false,
 
             // SAFETY: Extend lifetime and manage manually, see ListenerItem::poll for safety requirement
-            closure_ptr: SyncWrapper::new(unsafe { mem::transmute::<Unique<_>, Unique<_>>(closure) }),
+            closure_ptr: SyncWrapper::new(unsafe {
+                mem::transmute::<Unique<_>, Unique<_>>(closure)
+            }),
+            key: None,
+            id: None,
+
+            #[cfg(feature = "alloc")]
+            sleep: None,
+
+            #[cfg(feature = "alloc")]
+            done_signal: None,
+
+            #[cfg(not(feature = "alloc"))]
+            deferred: None,
         }
     }
 
-    fn update_waker(&mut self, waker: &Waker) {
+    pub(crate) const fn key(&self) -> Option<&ListenerKey> {
+        self.key.as_ref()
+    }
+
+    /// Id of listener if it is registered with [`EventSource::on_with_id`]
+    pub const fn id(&self) -> Option<ListenerId> {
+        self.id
+    }
+
+    pub(crate) fn update_waker(&mut self, waker: &Waker) {
+        if self.waker_fixed {
+            return;
+        }
+
         match self.waker {
-            Some(ref waker) if waker.will_wake(waker) => (),
+            Some(ref stored) if stored.will_wake(waker) => (),
 
             _ => {
                 self.waker = Some(waker.clone());
@@ -119,36 +345,200 @@ impl<T: ForLifetime> ListenerItem<T> {
         }
     }
 
+    /// Check if listener is finished already
+    pub const fn done(&self) -> bool {
+        self.done
+    }
+
+    /// Mark listener as finished
+    ///
+    /// Its task is woken once lock of listener list is released.
+    pub fn set_done(&mut self) {
+        self.done = true;
+    }
+
+    /// Mark listener as finished, inserting its waker to `wakers` if it was not finished
+    ///
+    /// Returns true if listener is finished by this call.
+    pub(crate) fn finish(&mut self, wakers: &mut WakerSet) -> bool {
+        if self.done {
+            return false;
+        }
+
+        self.done = true;
+        self.wake_finished(wakers);
+        true
+    }
+
+    /// Insert waker of finished listener to `wakers`
+    pub(crate) fn wake_finished(&mut self, wakers: &mut WakerSet) {
+        if !self.done {
+            return;
+        }
+
+        #[cfg(feature = "alloc")]
+        if let Some(ref signal) = self.done_signal {
+            signal.finish(wakers);
+        }
+
+        if let Some(waker) = self.waker.take() {
+            self.schedule_wake(waker, wakers);
+        }
+    }
+
+    /// Insert `waker` to `wakers`, deferring it if `wakers` is full
+    fn schedule_wake(&mut self, waker: Waker, wakers: &mut WakerSet) {
+        #[cfg(feature = "alloc")]
+        wakers.insert(waker);
+
+        #[cfg(not(feature = "alloc"))]
+        if let Err(waker) = wakers.try_insert(waker) {
+            self.deferred = Some(waker);
+        }
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    /// Move deferred waker to `wakers`, returns false if `wakers` is full
+    pub(crate) fn take_deferred(&mut self, wakers: &mut WakerSet) -> bool {
+        let waker = match self.deferred.take() {
+            Some(waker) => waker,
+            None => return true,
+        };
+
+        match wakers.try_insert(waker) {
+            Ok(()) => true,
+
+            Err(waker) => {
+                self.deferred = Some(waker);
+                false
+            }
+        }
+    }
+
+    /// Check if listener has waker registered
+    pub const fn has_waker(&self) -> bool {
+        self.waker.is_some()
+    }
+
+    /// Take snapshot of current listener state
+    pub const fn snapshot(&self) -> ListenerDebug {
+        ListenerDebug {
+            done: self.done,
+            has_waker: self.has_waker(),
+        }
+    }
+
     /// # Safety
     /// Calling this method is only safe if pointer to closure is valid
-    pub unsafe fn poll(&mut self, event: T::Of<'_>) -> bool {
-        let mut flow = ControlFlow {
-            done: self.done,
-            propagation: true,
-        };
+    ///
+    /// Waker of listener finished by this call is inserted to `wakers`.
+    /// Returns true if listener is finished by this call.
+    pub(crate) unsafe fn poll(
+        &mut self,
+        event: T::Of<'_>,
+        flow: &mut ControlFlow,
+        wakers: &mut WakerSet,
+    ) -> bool {
+        #[cfg(feature = "alloc")]
+        match self.sleep {
+            Some(ref condition) if !self.done && !(condition.0)() => return false,
+            Some(_) => self.sleep = None,
+            None => {}
+        }
 
-        self.closure_ptr.get_mut().as_mut()(event, &mut flow);
+        let was_done = self.done;
+        flow.done = was_done;
 
-        if flow.done && !self.done {
-            self.done = true;
+        self.closure_ptr.get_mut().as_mut()(event, flow);
 
-            if let Some(waker) = self.waker.take() {
-                waker.wake();
+        #[cfg(feature = "alloc")]
+        if let Some(condition) = flow.sleep.take() {
+            self.sleep = Some(condition);
+        }
+
+        if flow.done {
+            self.finish(wakers);
+        } else if flow.wake && (flow.wake_forced || !self.wake_pending) {
+            // Wake once until the task polls listener again, unless forced
+            if let Some(waker) = self.waker.clone() {
+                self.schedule_wake(waker, wakers);
+                self.wake_pending = true;
             }
         }
 
-        flow.propagation
+        !was_done && self.done
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Snapshot of listener state
+pub struct ListenerDebug {
+    /// Listener is finished
+    pub done: bool,
+
+    /// Listener has waker registered
+    pub has_waker: bool,
+}
+
 #[derive(Debug)]
 /// Control current listener's behaviour
 pub struct ControlFlow {
     done: bool,
     propagation: bool,
+    remaining: usize,
+    generation: u64,
+    acked: bool,
+    wake: bool,
+    wake_forced: bool,
+
+    #[cfg(feature = "alloc")]
+    output: Option<Box<dyn Any>>,
+
+    #[cfg(feature = "alloc")]
+    sleep: Option<SleepCondition>,
 }
 
 impl ControlFlow {
+    pub(crate) const fn new(remaining: usize) -> Self {
+        Self {
+            done: false,
+            propagation: true,
+            remaining,
+            generation: 0,
+            acked: false,
+            wake: false,
+            wake_forced: false,
+
+            #[cfg(feature = "alloc")]
+            output: None,
+
+            #[cfg(feature = "alloc")]
+            sleep: None,
+        }
+    }
+
+    pub(crate) fn with_generation(mut self, generation: u64) -> Self {
+        self.generation = generation;
+        self
+    }
+
+    /// Wake listener's task after this call without finishing it
+    ///
+    /// Requests are coalesced until the task polls listener again, so listener is woken once however many events arrive before the poll.
+    pub(crate) fn request_wake(&mut self) {
+        self.wake = true;
+    }
+
+    /// Wake listener's task after this call like [`ControlFlow::request_wake`], even if previous wake is not polled yet
+    pub(crate) fn force_wake(&mut self) {
+        self.wake = true;
+        self.wake_forced = true;
+    }
+
+    pub(crate) const fn propagation(&self) -> bool {
+        self.propagation
+    }
+
     /// Stop propagation of the current event
     pub fn stop_propagation(&mut self) {
         if self.propagation {
@@ -156,6 +546,39 @@ impl ControlFlow {
         }
     }
 
+    /// Let current event propagate even if propagation is stopped
+    pub(crate) fn resume_propagation(&mut self) {
+        self.propagation = true;
+    }
+
+    /// Acknowledge current event as handled and stop its propagation
+    pub fn ack(&mut self) {
+        self.acked = true;
+        self.stop_propagation();
+    }
+
+    pub(crate) const fn acked(&self) -> bool {
+        self.acked
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn set_output(&mut self, output: Box<dyn Any>) {
+        self.output = Some(output);
+    }
+
+    #[cfg(feature = "alloc")]
+    pub(crate) fn take_output(&mut self) -> Option<Box<dyn Any>> {
+        self.output.take()
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Skip listener without calling it until `condition` returns true
+    ///
+    /// `condition` is checked on every emission to listener while emitting, so it must be cheap.
+    pub fn sleep_until_condition(&mut self, condition: impl Fn() -> bool + Send + 'static) {
+        self.sleep = Some(SleepCondition(Box::new(condition)));
+    }
+
     /// Check if listener is finished already
     pub const fn done(&self) -> bool {
         self.done
@@ -167,4 +590,17 @@ impl ControlFlow {
             self.done = true;
         }
     }
+
+    /// Number of listeners after current listener in this emission
+    pub const fn remaining_listeners(&self) -> usize {
+        self.remaining
+    }
+
+    /// Generation of current emission
+    ///
+    /// It increases by one for every emission pass of [`struct@crate::EventSource`], so gap between generations is number of passes listener missed.
+    /// It is always zero for [`struct@crate::StaticEventSource`] and events delivered from listener's own task.
+    pub const fn generation(&self) -> u64 {
+        self.generation
+    }
 }
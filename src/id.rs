@@ -0,0 +1,145 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicUsize, Ordering};
+#[cfg(feature = "alloc")]
+use core::{
+    fmt::{self, Debug},
+    sync::atomic::AtomicU8,
+};
+
+#[cfg(feature = "alloc")]
+use higher_kinded_types::ForLifetime;
+
+#[cfg(feature = "alloc")]
+use crate::EventSource;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// Unique identifier of listener
+pub struct ListenerId(usize);
+
+impl ListenerId {
+    /// Allocate new unique id
+    ///
+    /// Uses pointer sized atomic, so it is available on targets without 64 bit atomics.
+    pub(crate) fn next() -> Self {
+        static NEXT: AtomicUsize = AtomicUsize::new(0);
+
+        Self(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+/// State of listener shared with its [`ListenerHandle`]s
+pub(crate) struct HandleState(AtomicU8);
+
+#[cfg(feature = "alloc")]
+impl HandleState {
+    /// Future is not polled yet
+    const IDLE: u8 = 0;
+
+    /// Listener is registered
+    const REGISTERED: u8 = 1;
+
+    /// Listener is cancelled by handle
+    const CANCELLED: u8 = 2;
+
+    /// Future is resolved or dropped
+    const ENDED: u8 = 3;
+
+    pub const fn new() -> Self {
+        Self(AtomicU8::new(Self::IDLE))
+    }
+
+    /// Mark listener as registered, returning false if it is cancelled
+    ///
+    /// Must be called while holding listener lock.
+    pub fn register(&self) -> bool {
+        match self.0.compare_exchange(
+            Self::IDLE,
+            Self::REGISTERED,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => true,
+            Err(state) => state != Self::CANCELLED,
+        }
+    }
+
+    /// Mark future as resolved or dropped
+    pub fn end(&self) {
+        self.0.store(Self::ENDED, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Handle cancelling listener from elsewhere
+///
+/// Created with [`EventSource::on_with_priority`].
+pub struct ListenerHandle<'a, T: ForLifetime> {
+    source: &'a EventSource<T>,
+    id: ListenerId,
+    state: Arc<HandleState>,
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: ForLifetime> ListenerHandle<'a, T> {
+    pub(crate) const fn new(
+        source: &'a EventSource<T>,
+        id: ListenerId,
+        state: Arc<HandleState>,
+    ) -> Self {
+        Self { source, id, state }
+    }
+
+    /// Id of listener
+    pub const fn id(&self) -> ListenerId {
+        self.id
+    }
+
+    /// Remove listener and wake its task, completing its future
+    ///
+    /// If future is not polled yet, it completes on first poll without being registered.
+    /// Returns false if listener is already cancelled, finished or its future is dropped before this call. See [`EventSource::cancel`]
+    pub fn cancel(&self) -> bool {
+        // Stored before acquiring lock, so a future registering after this call observes it
+        match self.state.0.swap(HandleState::CANCELLED, Ordering::Relaxed) {
+            HandleState::IDLE => true,
+
+            HandleState::REGISTERED => match self.source.remove(self.id) {
+                Some(finished) => !finished,
+
+                // Future is dropped concurrently
+                None => false,
+            },
+
+            _ => false,
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ForLifetime> Clone for ListenerHandle<'_, T> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source,
+            id: self.id,
+            state: self.state.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ForLifetime> Debug for ListenerHandle<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListenerHandle")
+            .field("id", &self.id)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,66 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use core::any::Any;
+
+#[cfg(feature = "alloc")]
+trait DynKey: Any + Send {
+    fn as_any(&self) -> &dyn Any;
+
+    fn cmp_dyn(&self, other: &dyn DynKey) -> Option<Ordering>;
+}
+
+#[cfg(feature = "alloc")]
+impl<K: Ord + Send + 'static> DynKey for K {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn cmp_dyn(&self, other: &dyn DynKey) -> Option<Ordering> {
+        Some(self.cmp(other.as_any().downcast_ref::<K>()?))
+    }
+}
+
+#[cfg(feature = "alloc")]
+/// Type erased ordering key of listener
+pub(crate) struct ListenerKey(Box<dyn DynKey>);
+
+#[cfg(not(feature = "alloc"))]
+/// Type erased ordering key of listener, which cannot exist without `alloc` feature
+pub(crate) enum ListenerKey {}
+
+impl ListenerKey {
+    #[cfg(feature = "alloc")]
+    pub fn new<K: Ord + Send + 'static>(key: K) -> Self {
+        Self(Box::new(key))
+    }
+
+    /// Compare keys. Returns [`Option::None`] if keys are different types.
+    pub fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        #[cfg(feature = "alloc")]
+        return self.0.cmp_dyn(&*other.0);
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            let _ = other;
+            match *self {}
+        }
+    }
+}
+
+impl Debug for ListenerKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ListenerKey")
+    }
+}
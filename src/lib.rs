@@ -7,22 +7,93 @@
 #![no_std]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
 #[doc(hidden)]
 pub mod __private;
+mod adapter;
+#[cfg(feature = "alloc")]
+mod bounded;
+mod buffered;
+#[cfg(feature = "alloc")]
+mod done;
+mod fixed;
 mod future;
+mod id;
+mod key;
+mod mapped;
+#[cfg(feature = "alloc")]
+mod owned;
+#[cfg(feature = "std")]
+mod panic;
+#[cfg(feature = "rayon")]
+mod parallel;
+#[cfg(feature = "alloc")]
+mod queued;
+#[cfg(feature = "alloc")]
+mod replay;
 mod sealed;
+mod sharded;
+mod shared;
+#[cfg(feature = "alloc")]
+mod split;
+#[cfg(feature = "alloc")]
+mod subscription;
 mod types;
+mod waker;
 
-pub use future::{ControlFlow, EventFnFuture};
+#[cfg(feature = "alloc")]
+pub use adapter::RaceListener;
+pub use adapter::{CancelToken, Clock, RetryOutcome, StateStep};
+#[cfg(feature = "alloc")]
+pub use bounded::BoundedEventSource;
+#[cfg(feature = "alloc")]
+pub use buffered::DropPolicy;
+pub use buffered::EventSlot;
+#[cfg(feature = "alloc")]
+pub use done::DoneFuture;
+pub use fixed::{CapacityError, StaticEventEmitter, StaticEventFnFuture, StaticEventSource};
+pub use future::{ControlFlow, EventFnFuture, ListenerDebug, ListenerItem};
+#[cfg(feature = "alloc")]
+pub use id::ListenerHandle;
+pub use id::ListenerId;
+pub use mapped::MappedSource;
+#[cfg(feature = "alloc")]
+pub use owned::OwnedEventFnFuture;
+#[cfg(feature = "std")]
+pub use panic::{ListenerError, ListenerPanic, PanicReport};
+#[cfg(feature = "alloc")]
+pub use queued::QueuedEventSource;
+#[cfg(feature = "alloc")]
+pub use replay::ReplayEventSource;
+pub use sharded::ShardedEventSource;
+pub use shared::SharedEmitter;
+#[cfg(feature = "alloc")]
+pub use split::{Emitter, Subscriber};
+#[cfg(feature = "alloc")]
+pub use subscription::{Subscription, SubscriptionScope};
 
-use core::fmt::{self, Debug};
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::any::Any;
+use core::{
+    fmt::{self, Debug},
+    sync::atomic::{AtomicBool, AtomicUsize, Ordering},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
 
 use higher_kinded_types::ForLifetime;
-use parking_lot::Mutex;
+use parking_lot::{Mutex, MutexGuard};
 
 use pin_list::{id::Unchecked, CursorMut};
 
-use types::{NodeTypes, PinList};
+use types::{ListenerList, NodeTypes};
+use waker::WakerSet;
 
 #[macro_export]
 /// Higher kinded type helper for [`struct@EventSource`]
@@ -40,47 +111,663 @@ macro_rules! emit {
     };
 }
 
+#[macro_export]
+/// Listen events matching enum variant, binding its payload. Other variants are skipped.
+///
+/// ```
+/// # use event_source::{on_variant, EventSource};
+/// enum Event {
+///     A(i32),
+///     B(&'static str),
+/// }
+///
+/// let source: EventSource!(Event) = EventSource::new();
+/// let _future = on_variant!(source, Event::A, |value, flow| {
+///     let _: i32 = value;
+///     flow.set_done();
+/// });
+/// ```
+macro_rules! on_variant {
+    ($source: expr, $variant: path, |$payload: pat_param, $flow: pat_param| $body: expr) => {
+        $source.on(|event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if let $variant($payload) = event {
+                let $flow = flow;
+                $body
+            }
+        })
+    };
+}
+
 /// Event source
 pub struct EventSource<T: ForLifetime> {
-    list: Mutex<PinList<T>>,
+    list: Mutex<ListenerList<T>>,
+    closed: AtomicBool,
+    /// Id of thread running [`EventSource::for_each_listener`], zero if none
+    iterating: AtomicUsize,
+
+    #[cfg(feature = "std")]
+    error_sink: parking_lot::RwLock<Option<Box<panic::ErrorSink>>>,
 }
 
 impl<T: ForLifetime> Debug for EventSource<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("EventSource")
             .field("list", &self.list)
+            .field("closed", &self.closed)
             .finish()
     }
 }
 
+impl<T: ForLifetime> Default for EventSource<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<T: ForLifetime> EventSource<T> {
     /// Create new [`struct@EventSource`]
     pub const fn new() -> Self {
         Self {
             // SAFETY: There is only one variant of [`Pinlist`]
-            list: Mutex::new(PinList::new(unsafe { Unchecked::new() })),
+            list: Mutex::new(ListenerList::new(pin_list::PinList::new(unsafe {
+                Unchecked::new()
+            }))),
+            closed: AtomicBool::new(false),
+            iterating: AtomicUsize::new(0),
+
+            #[cfg(feature = "std")]
+            error_sink: parking_lot::RwLock::new(None),
         }
     }
 
+    /// Close this [`struct@EventSource`]
+    ///
+    /// Every registered listener is marked as finished. Listeners registered after closing finish immediately and events are not emitted anymore.
+    pub fn close(&self) {
+        // Release pairs with Acquire loads in fast paths, so a reader observing closed state also observes everything before close.
+        // Slow paths check it again while holding the lock, which is acquired here after the store.
+        self.closed.store(true, Ordering::Release);
+
+        self.with_list(|list, wakers| {
+            let mut cursor = list.list.cursor_front_mut();
+            while let Some(item) = cursor.protected_mut() {
+                item.finish(wakers);
+                cursor.move_next();
+            }
+        });
+    }
+
+    /// Check if this [`struct@EventSource`] is closed
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
     /// Create [`EventEmitter`] for this [`struct@EventSource`]
+    ///
+    /// `emit_fn` is not called if source is closed.
+    /// Listener list is locked during `emit_fn`, so concurrent emissions never interleave and every listener observes them in same order.
+    ///
+    /// If a listener panics, lock is released while unwinding and source stays usable without recovery.
+    /// Panicked listener stays registered until its future is dropped.
     pub fn with_emitter(&self, emit_fn: impl FnOnce(EventEmitter<T>)) {
-        let mut list = self.list.lock();
+        if self.is_closed() {
+            return;
+        }
+
+        self.with_list(|list, wakers| emit_fn(EventEmitter::new(list, wakers)));
+    }
+
+    /// Create [`EventEmitter`] for this [`struct@EventSource`], collecting outputs of notified listeners
+    ///
+    /// Outputs of listeners registered with [`EventSource::on_returning`] are collected in emission order.
+    /// Outputs of other types than `R` are ignored.
+    #[cfg(feature = "alloc")]
+    pub fn with_emitter_collect<R: 'static>(
+        &self,
+        emit_fn: impl FnOnce(&mut EventEmitter<T>),
+    ) -> Vec<R> {
+        if self.is_closed() {
+            return Vec::new();
+        }
+
+        let outputs = self.with_list(|list, wakers| {
+            let mut emitter = EventEmitter::new(list, wakers);
+            emitter.outputs = Some(Vec::new());
 
-        emit_fn(EventEmitter {
-            cursor: list.cursor_front_mut(),
+            emit_fn(&mut emitter);
+            emitter.outputs.take().unwrap_or_default()
         });
+
+        outputs
+            .into_iter()
+            .filter_map(|output| output.downcast().ok().map(|output| *output))
+            .collect()
+    }
+
+    /// Create [`EventEmitter`] for this [`struct@EventSource`], waiting for lock at most `timeout`
+    ///
+    /// Returns [`Option::None`] without calling `emit_fn` if lock is not acquired in time or source is closed.
+    pub fn try_with_emitter_for<R>(
+        &self,
+        timeout: Duration,
+        emit_fn: impl FnOnce(EventEmitter<T>) -> R,
+    ) -> Option<R> {
+        if self.is_closed() {
+            return None;
+        }
+
+        self.try_with_list(
+            |list| list.try_lock_for(timeout),
+            |list, wakers| emit_fn(EventEmitter::new(list, wakers)),
+        )
+    }
+
+    /// Create [`EventEmitter`] continuing from position saved in `token`
+    ///
+    /// Returns false without calling `emit_fn` if source is closed or any listener was registered or removed after `token` was created.
+    ///
+    /// Paused emission does not hold the lock, so events emitted before resuming are delivered to every listener first.
+    /// This can be used to deliver high priority events in the middle of paused emission.
+    pub fn with_emitter_from(
+        &self,
+        token: ResumeToken,
+        emit_fn: impl FnOnce(EventEmitter<T>),
+    ) -> bool {
+        if self.is_closed() {
+            return false;
+        }
+
+        self.with_list(|list, wakers| {
+            if list.version != token.version {
+                return false;
+            }
+
+            let mut emitter = EventEmitter::new(list, wakers);
+            for _ in 0..token.position {
+                if emitter.skip().is_none() {
+                    return false;
+                }
+            }
+
+            emit_fn(emitter);
+            true
+        })
+    }
+
+    /// Emit event created from `state` to every listeners exclusively
+    ///
+    /// Event is created with `make_event` for each listener, reborrowing `state`.
+    /// Whole emission is done while listener list is locked and it cannot be paused, so no other emission can observe or modify `state` in between.
+    pub fn emit_exclusive<S: ?Sized>(
+        &self,
+        state: &mut S,
+        mut make_event: impl for<'b> FnMut(&'b mut S) -> T::Of<'b>,
+    ) {
+        if self.is_closed() {
+            return;
+        }
+
+        self.with_list(|list, wakers| {
+            let mut emitter = EventEmitter::new(list, wakers);
+            while emitter.emit_next(make_event(state)).is_some() {}
+        });
+    }
+
+    /// Call `emit_fn` with [`EventEmitter`] if `should` returns true with current listener count.
+    ///
+    /// Event is created inside `emit_fn` only if `should` returns true, so events cannot be cloned like `&mut T` are emitted same as [`emit!`].
+    /// Both closures are called while listener list is locked.
+    pub fn emit_if(
+        &self,
+        should: impl FnOnce(usize) -> bool,
+        emit_fn: impl FnOnce(EventEmitter<T>),
+    ) {
+        if self.is_closed() {
+            return;
+        }
+
+        self.with_list(|list, wakers| {
+            if should(list.len) {
+                emit_fn(EventEmitter::new(list, wakers));
+            }
+        });
+    }
+
+    /// Emit event to every listeners, cloning it for each listener.
+    ///
+    /// Returns number of listeners notified. Event is moved into last listener, so it is not cloned if there is only one or no listener.
+    pub fn emit_owned(&self, event: T::Of<'_>) -> usize
+    where
+        for<'a> T::Of<'a>: Clone,
+    {
+        if self.is_closed() {
+            return 0;
+        }
+
+        self.with_list(|list, wakers| EventEmitter::new(list, wakers).emit_cloned(event))
+    }
+
+    /// Try emitting event to every listeners like [`EventSource::emit_owned`] without blocking
+    ///
+    /// Returns [`Poll::Pending`] if listener list is locked by others, waking task immediately to retry on next poll.
+    /// Task is not notified when lock is released, so polling this method spins the executor while lock is contended.
+    /// Prefer [`EventSource::emit_owned`] if blocking is acceptable.
+    pub fn poll_emit(&self, cx: &mut Context<'_>, event: T::Of<'_>) -> Poll<usize>
+    where
+        for<'a> T::Of<'a>: Clone,
+    {
+        if self.is_closed() {
+            return Poll::Ready(0);
+        }
+
+        match self.try_with_list(Mutex::try_lock, |list, wakers| {
+            EventEmitter::new(list, wakers).emit_cloned(event)
+        }) {
+            Some(notified) => Poll::Ready(notified),
+
+            None => {
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    /// Emit event to every listeners like [`EventSource::emit_owned`], reporting the result
+    pub fn emit_and_report(&self, event: T::Of<'_>) -> EmitReport
+    where
+        for<'a> T::Of<'a>: Clone,
+    {
+        if self.is_closed() {
+            return EmitReport::default();
+        }
+
+        self.with_list(|list, wakers| {
+            let mut emitter = EventEmitter::new(list, wakers);
+            let notified = emitter.emit_cloned(event);
+
+            EmitReport {
+                notified,
+                completed: emitter.completed,
+            }
+        })
+    }
+
+    /// Emit event to listeners in order until one of them acknowledges it using [`ControlFlow::ack`]
+    ///
+    /// Event is cloned for each listener like [`EventSource::emit_owned`]. Returns true if event is acknowledged.
+    pub fn emit_until_ack(&self, event: T::Of<'_>) -> bool
+    where
+        for<'a> T::Of<'a>: Clone,
+    {
+        if self.is_closed() {
+            return false;
+        }
+
+        self.with_list(|list, wakers| {
+            let mut emitter = EventEmitter::new(list, wakers);
+            emitter.emit_cloned(event);
+
+            emitter.acked
+        })
+    }
+
+    /// Emit event to listeners in order until one of them consumes it using [`ControlFlow::ack`]
+    ///
+    /// Returns id of listener consumed the event, or [`Option::None`] if it fell through or consumed by listener without id.
+    #[cfg_attr(
+        feature = "alloc",
+        doc = " Combine with [`EventSource::on_with_priority`] to order handlers."
+    )]
+    pub fn emit_chain(&self, event: T::Of<'_>) -> Option<ListenerId>
+    where
+        for<'a> T::Of<'a>: Clone,
+    {
+        if self.is_closed() {
+            return None;
+        }
+
+        self.with_list(|list, wakers| {
+            let mut emitter = EventEmitter::new(list, wakers);
+            loop {
+                let id = emitter.cursor.protected()?.id();
+                let next = emitter.emit_next(event.clone());
+
+                if emitter.acked {
+                    return id;
+                }
+                next?;
+            }
+        })
+    }
+
+    /// Emit every events in order, holding lock for whole batch
+    ///
+    /// Each event is cloned for each listener like [`EventSource::emit_owned`].
+    /// Listeners cannot be registered or removed until batch is finished.
+    pub fn emit_batch<'a, I>(&self, events: I)
+    where
+        I: IntoIterator<Item = T::Of<'a>>,
+        T::Of<'a>: Clone,
+    {
+        if self.is_closed() {
+            return;
+        }
+
+        self.with_list(|list, wakers| {
+            for event in events {
+                EventEmitter::new(list, wakers).emit_cloned(event);
+            }
+        });
+    }
+
+    /// Emit event only to listener at `index`
+    ///
+    /// Returns true if listener existed at `index`.
+    pub fn emit_to_index(&self, index: usize, event: T::Of<'_>) -> bool {
+        if self.is_closed() {
+            return false;
+        }
+
+        self.with_list(|list, wakers| {
+            let mut emitter = EventEmitter::new(list, wakers);
+            for _ in 0..index {
+                if emitter.skip().is_none() {
+                    return false;
+                }
+            }
+
+            if emitter.remaining() == 0 {
+                return false;
+            }
+
+            emitter.emit_next(event);
+            true
+        })
+    }
+
+    /// Emit event only to listener with `id`
+    ///
+    /// Returns true if listener with `id` is registered.
+    pub fn emit_to_id(&self, id: ListenerId, event: T::Of<'_>) -> bool {
+        if self.is_closed() {
+            return false;
+        }
+
+        self.with_list(|list, wakers| {
+            let mut emitter = EventEmitter::new(list, wakers);
+            loop {
+                match emitter.cursor.protected() {
+                    Some(item) if item.id() == Some(id) => break,
+                    Some(_) => {
+                        emitter.skip();
+                    }
+                    None => return false,
+                }
+            }
+
+            emitter.emit_next(event);
+            true
+        })
+    }
+
+    /// Remove listener with `id` and wake its task, completing its future
+    ///
+    /// Returns true if listener with `id` is registered and was not finished.
+    pub fn cancel(&self, id: ListenerId) -> bool {
+        self.remove(id) == Some(false)
+    }
+
+    /// Remove listener with `id`, returning whether it was finished already
+    fn remove(&self, id: ListenerId) -> Option<bool> {
+        self.with_list(|list, wakers| {
+            let mut item = list.remove(|item| item.id() == Some(id))?;
+
+            Some(!item.finish(wakers))
+        })
+    }
+
+    /// Apply `f` to every registered listener in emission order
+    ///
+    /// Listener list is locked while iterating. Using this [`struct@EventSource`] inside `f` panics instead of deadlocking.
+    /// Tasks of listeners finished by `f` are woken after lock is released.
+    pub fn for_each_listener(&self, mut f: impl FnMut(&mut ListenerItem<T>)) {
+        self.with_list(|list, wakers| {
+            let _guard = IteratingGuard::new(&self.iterating);
+            let mut cursor = list.list.cursor_front_mut();
+
+            while let Some(item) = cursor.protected_mut() {
+                f(item);
+                item.wake_finished(wakers);
+                cursor.move_next();
+            }
+        });
+    }
+
+    /// Lock listener list and call `f`, waking wakers collected by `f` after lock is released
+    fn with_list<R>(&self, f: impl FnOnce(&mut ListenerList<T>, &mut WakerSet) -> R) -> R {
+        let mut wakers = WakerSet::new();
+        // Lock guard is temporary, so it is released before wakers are woken even while unwinding
+        let res = f(&mut *self.lock_list(), &mut wakers);
+        self.wake(wakers);
+
+        res
+    }
+
+    /// Lock listener list, panics if lock is held by [`EventSource::for_each_listener`] on current thread
+    fn lock_list(&self) -> MutexGuard<'_, ListenerList<T>> {
+        match self.list.try_lock() {
+            Some(list) => list,
+            None => {
+                assert_ne!(
+                    self.iterating.load(Ordering::Relaxed),
+                    thread_id(),
+                    "EventSource is used inside for_each_listener"
+                );
+
+                self.list.lock()
+            }
+        }
+    }
+
+    /// Lock listener list using `lock` and call `f` like [`EventSource::with_list`], returns [`Option::None`] if lock is not acquired
+    fn try_with_list<'a, R>(
+        &'a self,
+        lock: impl FnOnce(&'a Mutex<ListenerList<T>>) -> Option<MutexGuard<'a, ListenerList<T>>>,
+        f: impl FnOnce(&mut ListenerList<T>, &mut WakerSet) -> R,
+    ) -> Option<R> {
+        let mut wakers = WakerSet::new();
+        let res = f(&mut *lock(&self.list)?, &mut wakers);
+        self.wake(wakers);
+
+        Some(res)
+    }
+
+    /// Wake `wakers` after lock is released, then wake wakers deferred in listeners
+    fn wake(&self, wakers: WakerSet) {
+        #[cfg(not(feature = "alloc"))]
+        let mut overflowed = wakers.overflowed();
+        drop(wakers);
+
+        // Each batch is woken after lock is released, until no waker is deferred
+        #[cfg(not(feature = "alloc"))]
+        while overflowed {
+            let mut wakers = WakerSet::new();
+            let mut list = self.lock_list();
+            let mut cursor = list.list.cursor_front_mut();
+            while let Some(item) = cursor.protected_mut() {
+                if !item.take_deferred(&mut wakers) {
+                    break;
+                }
+
+                cursor.move_next();
+            }
+            drop(list);
+
+            overflowed = wakers.overflowed();
+        }
+    }
+
+    /// Take snapshot of first listener without emitting
+    pub fn peek_front(&self) -> Option<ListenerDebug> {
+        self.lock_list()
+            .list
+            .cursor_front()
+            .protected()
+            .map(ListenerItem::snapshot)
+    }
+
+    /// Number of listeners currently registered
+    pub fn listener_count(&self) -> usize {
+        self.lock_list().len
+    }
+
+    /// Set callback invoked with new listener count whenever a listener is registered or removed
+    ///
+    /// Callback is invoked while listener list is locked, so it must not access this source.
+    #[cfg(feature = "alloc")]
+    pub fn on_listeners_changed(&self, f: impl Fn(usize) + Send + 'static) {
+        self.lock_list().on_changed = Some(Box::new(f));
+    }
+
+    /// Ids of registered listeners in emission order
+    ///
+    /// Only listeners registered with [`EventSource::on_with_id`] have ids.
+    #[cfg(feature = "alloc")]
+    pub fn listener_ids(&self) -> Vec<ListenerId> {
+        let mut ids = Vec::new();
+        self.for_each_listener(|item| ids.extend(item.id()));
+
+        ids
+    }
+
+    /// Number of registered listeners without waker
+    ///
+    /// Listeners registered without being polled, like subscriptions, and finished listeners have no waker.
+    pub fn count_without_waker(&self) -> usize {
+        let mut count = 0;
+        self.for_each_listener(|item| {
+            if !item.has_waker() {
+                count += 1;
+            }
+        });
+
+        count
+    }
+
+    /// Number of listeners which are finished but still registered
+    ///
+    /// These listeners are still visited by every emission until their futures are dropped.
+    pub fn count_done(&self) -> usize {
+        let mut count = 0;
+        self.for_each_listener(|item| {
+            if item.done() {
+                count += 1;
+            }
+        });
+
+        count
+    }
+
+    /// Assert there is no registered listener
+    ///
+    /// # Panics
+    /// Panics with number of listeners if any listener is registered
+    #[track_caller]
+    pub fn assert_no_listeners(&self) {
+        let count = self.listener_count();
+        assert!(
+            count == 0,
+            "EventSource has {count} listener(s) registered, expected none"
+        );
     }
 
     /// Listen events
     ///
     /// It can be called after woken if another event occurred before task continue
-    pub fn on<F>(&self, listener: F) -> EventFnFuture<F, T>
+    pub fn on<F>(&self, listener: F) -> EventFnFuture<'_, F, T>
     where
         F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
     {
         EventFnFuture::new(self, listener)
     }
 
+    /// Listen events, returns [`ClosedError`] if source is already closed
+    ///
+    /// See [`EventSource::on`]
+    pub fn try_on<F>(&self, listener: F) -> Result<EventFnFuture<'_, F, T>, ClosedError>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        if self.is_closed() {
+            return Err(ClosedError);
+        }
+
+        Ok(EventFnFuture::new(self, listener))
+    }
+
+    /// Listen events, returning unique [`ListenerId`] of listener
+    ///
+    /// Returned id can be used to target listener later, using [`EventSource::emit_to_id`].
+    pub fn on_with_id<F>(&self, listener: F) -> (ListenerId, EventFnFuture<'_, F, T>)
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let id = ListenerId::next();
+
+        (id, EventFnFuture::new(self, listener).with_id(id))
+    }
+
+    /// Listen events, waking `waker` instead of waker of polling task
+    ///
+    /// `waker` is used for every wake of listener and wakers from polling task are ignored.
+    pub fn on_with_waker<F>(&self, waker: Waker, listener: F) -> EventFnFuture<'_, F, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        EventFnFuture::new(self, listener).with_waker(waker)
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Listen events, ordered by `key`
+    ///
+    /// Listeners with keys of same type are called in ascending order of key after registered.
+    /// Listeners with equal keys are called in registration order.
+    pub fn on_keyed<K, F>(&self, key: K, listener: F) -> EventFnFuture<'_, F, T>
+    where
+        K: Ord + Send + 'static,
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        EventFnFuture::new(self, listener).with_key(key::ListenerKey::new(key))
+    }
+
+    #[cfg(feature = "alloc")]
+    /// Listen events, ordered by `priority` like [`EventSource::on_keyed`]
+    ///
+    /// Returned [`ListenerHandle`] can cancel listener from elsewhere, even before its future is polled.
+    pub fn on_with_priority<K, F>(
+        &self,
+        priority: K,
+        listener: F,
+    ) -> (EventFnFuture<'_, F, T>, ListenerHandle<'_, T>)
+    where
+        K: Ord + Send + 'static,
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let (id, future) = self.on_with_id(listener);
+        let state = alloc::sync::Arc::new(id::HandleState::new());
+
+        (
+            future
+                .with_key(key::ListenerKey::new(priority))
+                .with_handle(state.clone()),
+            ListenerHandle::new(self, id, state),
+        )
+    }
+
     /// Listen event until listener returns [`Option::Some`]
     ///
     /// Unlike [`EventSource::on`] it will ignore every events once listener is done or returns with [`Option::Some`].
@@ -107,24 +794,187 @@ impl<T: ForLifetime> EventSource<T> {
     }
 }
 
+/// Nonzero id of current thread
+fn thread_id() -> usize {
+    use parking_lot::lock_api::GetThreadId;
+
+    parking_lot::RawThreadId::INIT.nonzero_thread_id().get()
+}
+
+/// Marks current thread as iterating listeners until dropped, even while unwinding
+struct IteratingGuard<'a>(&'a AtomicUsize);
+
+impl<'a> IteratingGuard<'a> {
+    fn new(iterating: &'a AtomicUsize) -> Self {
+        iterating.store(thread_id(), Ordering::Relaxed);
+        Self(iterating)
+    }
+}
+
+impl Drop for IteratingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.store(0, Ordering::Relaxed);
+    }
+}
+
 /// Struct for emitting values for each listeners
 #[derive(Debug)]
 pub struct EventEmitter<'a, T: ForLifetime> {
     cursor: CursorMut<'a, NodeTypes<T>>,
+    wakers: &'a mut WakerSet,
+    position: usize,
+    len: usize,
+    version: usize,
+    generation: u64,
+    completed: usize,
+    acked: bool,
+
+    #[cfg(feature = "alloc")]
+    outputs: Option<Vec<Box<dyn Any>>>,
 }
 
-impl<T: ForLifetime> EventEmitter<'_, T> {
+impl<'a, T: ForLifetime> EventEmitter<'a, T> {
+    /// Wakers of finished listeners are woken when `wakers` is dropped,
+    /// so it must outlive lock guard of `list` to wake them after lock is released.
+    fn new(list: &'a mut ListenerList<T>, wakers: &'a mut WakerSet) -> Self {
+        list.generation = list.generation.wrapping_add(1);
+
+        Self {
+            generation: list.generation,
+            len: list.len,
+            version: list.version,
+            cursor: list.list.cursor_front_mut(),
+            wakers,
+            position: 0,
+            completed: 0,
+            acked: false,
+
+            #[cfg(feature = "alloc")]
+            outputs: None,
+        }
+    }
+
+    fn emit_cloned<'b>(&mut self, event: T::Of<'b>) -> usize
+    where
+        T::Of<'b>: Clone,
+    {
+        let mut count = 0;
+
+        while self.cursor.protected().is_some() {
+            count += 1;
+
+            let last = {
+                let mut next = self.cursor.as_shared();
+                next.move_next();
+                next.protected().is_none()
+            };
+
+            if last {
+                self.emit_next(event);
+                break;
+            }
+
+            if self.emit_next(event.clone()).is_none() {
+                break;
+            }
+        }
+
+        count
+    }
+
     /// Emit event to next listener
     pub fn emit_next(&mut self, event: T::Of<'_>) -> Option<()> {
+        let remaining = self.remaining().checked_sub(1)?;
         let node = self.cursor.protected_mut()?;
 
+        let mut flow = ControlFlow::new(remaining).with_generation(self.generation);
         // SAFETY: Listener closure pointer is valid
-        if unsafe { !node.poll(event) } {
+        if unsafe { node.poll(event, &mut flow, self.wakers) } {
+            self.completed += 1;
+        }
+        if flow.acked() {
+            self.acked = true;
+        }
+        #[cfg(feature = "alloc")]
+        if let (Some(outputs), Some(output)) = (&mut self.outputs, flow.take_output()) {
+            outputs.push(output);
+        }
+
+        if !flow.propagation() {
             return None;
         }
 
         self.cursor.move_next();
+        self.position += 1;
+
+        Some(())
+    }
+
+    /// Skip next listener without emitting
+    pub fn skip(&mut self) -> Option<()> {
+        self.cursor.protected()?;
+
+        self.cursor.move_next();
+        self.position += 1;
 
         Some(())
     }
+
+    /// Number of listeners the cursor has passed
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Number of listeners not emitted yet
+    pub const fn remaining(&self) -> usize {
+        self.len - self.position
+    }
+
+    /// Number of listeners finished by this emitter
+    pub const fn completed(&self) -> usize {
+        self.completed
+    }
+
+    /// Check if any listener acknowledged event emitted by this emitter
+    pub const fn acked(&self) -> bool {
+        self.acked
+    }
+
+    /// Save current position to continue emission later using [`EventSource::with_emitter_from`]
+    pub const fn token(&self) -> ResumeToken {
+        ResumeToken {
+            position: self.position,
+            version: self.version,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Result of [`EventSource::emit_and_report`]
+pub struct EmitReport {
+    /// Number of listeners notified
+    pub notified: usize,
+
+    /// Number of listeners finished during emission
+    pub completed: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Saved position of [`EventEmitter`]
+pub struct ResumeToken {
+    position: usize,
+    version: usize,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Error returned when [`struct@EventSource`] is already closed
+pub struct ClosedError;
+
+impl fmt::Display for ClosedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("event source is closed")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ClosedError {}
@@ -0,0 +1,82 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    fmt::{self, Debug},
+    marker::PhantomData,
+};
+
+use higher_kinded_types::ForLifetime;
+
+use crate::{ControlFlow, EventFnFuture, EventSource};
+
+/// View of [`struct@EventSource`] with its events mapped to `U`
+///
+/// Listeners are registered on the underlying source directly, so they are driven by its emission.
+pub struct MappedSource<'a, T: ForLifetime, U, M> {
+    source: &'a EventSource<T>,
+    map: M,
+    _phantom: PhantomData<fn() -> U>,
+}
+
+impl<T: ForLifetime, U, M> Debug for MappedSource<'_, T, U, M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MappedSource")
+            .field("source", &self.source)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a, T, U, M> MappedSource<'a, T, U, M>
+where
+    T: ForLifetime,
+    M: Fn(T::Of<'_>) -> U + Sync,
+{
+    /// Listen mapped events
+    ///
+    /// See [`EventSource::on`]
+    pub fn on<'s, F>(
+        &'s self,
+        mut listener: F,
+    ) -> EventFnFuture<'s, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send + 's, T>
+    where
+        F: FnMut(U, &mut ControlFlow) + Send + 's,
+    {
+        let map = &self.map;
+
+        self.source
+            .on(move |event, flow| listener(map(event), flow))
+    }
+
+    /// Listen mapped event until listener returns [`Option::Some`]
+    ///
+    /// See [`EventSource::once`]
+    pub async fn once<F, R>(&self, mut listener: F) -> Option<R>
+    where
+        F: FnMut(U, &mut ControlFlow) -> Option<R> + Send,
+        R: Send,
+    {
+        let map = &self.map;
+
+        self.source
+            .once(move |event, flow| listener(map(event), flow))
+            .await
+    }
+}
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Create view of this [`struct@EventSource`] with events mapped using `map`
+    pub fn map<U, M>(&self, map: M) -> MappedSource<'_, T, U, M>
+    where
+        M: Fn(T::Of<'_>) -> U + Sync,
+    {
+        MappedSource {
+            source: self,
+            map,
+            _phantom: PhantomData,
+        }
+    }
+}
@@ -0,0 +1,86 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use alloc::sync::Arc;
+use higher_kinded_types::ForLifetime;
+
+use crate::{
+    future::{drop_node, poll_node, ListenerOptions},
+    sealed::Sealed,
+    types::Node,
+    ControlFlow, EventSource,
+};
+
+pin_project_lite::pin_project!(
+    #[derive(Debug)]
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    /// Future created with [`EventSource::on_owned`]
+    ///
+    /// Unlike [`crate::EventFnFuture`], it keeps [`struct@EventSource`] alive using [`Arc`],
+    /// so it is `'static` and can be spawned if listener is `'static`.
+    pub struct OwnedEventFnFuture<F, T: ForLifetime> {
+        source: Arc<EventSource<T>>,
+
+        #[pin]
+        listener: Sealed<F>,
+
+        #[pin]
+        node: Node<T>,
+
+        options: ListenerOptions,
+    }
+
+    impl<F, T: ForLifetime> PinnedDrop for OwnedEventFnFuture<F, T> {
+        fn drop(this: Pin<&mut Self>) {
+            let project = this.project();
+            drop_node(project.source, project.node);
+        }
+    }
+);
+
+impl<T: ForLifetime, F: FnMut(T::Of<'_>, &mut ControlFlow) + Send> Future
+    for OwnedEventFnFuture<F, T>
+{
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+
+        poll_node(this.source, this.listener, this.node, this.options, cx)
+    }
+}
+
+#[cfg(feature = "futures")]
+impl<T: ForLifetime, F: FnMut(T::Of<'_>, &mut ControlFlow) + Send> futures_core::FusedFuture
+    for OwnedEventFnFuture<F, T>
+{
+    fn is_terminated(&self) -> bool {
+        self.options.resolved
+    }
+}
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Listen events, keeping this [`struct@EventSource`] alive
+    ///
+    /// See [`EventSource::on`]
+    pub fn on_owned<F>(self: &Arc<Self>, listener: F) -> OwnedEventFnFuture<F, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        OwnedEventFnFuture {
+            source: self.clone(),
+            listener: Sealed::new(listener),
+            node: pin_list::Node::new(),
+            options: ListenerOptions::new(),
+        }
+    }
+}
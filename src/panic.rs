@@ -0,0 +1,184 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    any::Any,
+    fmt::{self, Debug, Display},
+};
+use std::{
+    backtrace::Backtrace,
+    boxed::Box,
+    error::Error,
+    panic::{self, AssertUnwindSafe},
+};
+
+use higher_kinded_types::ForLifetime;
+
+use crate::{ControlFlow, EventEmitter, EventFnFuture, EventSource};
+
+pub(crate) type ErrorSink = dyn Fn(ListenerError) + Send + Sync;
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Set sink receiving failures of listeners on this source
+    ///
+    /// Panics from [`EventSource::on_panic_safe`], [`EventSource::on_panic_report`] listeners and errors from [`EventSource::on_result`] listeners are passed to the sink.
+    /// Sink is invoked during emission, so it must not set error sink of this source.
+    pub fn with_error_sink(&self, sink: impl Fn(ListenerError) + Send + Sync + 'static) {
+        *self.error_sink.write() = Some(Box::new(sink));
+    }
+
+    fn report_error(&self, error: ListenerError) {
+        if let Some(ref sink) = *self.error_sink.read() {
+            sink(error);
+        }
+    }
+
+    /// Listen events, passing errors returned from listener to error sink
+    ///
+    /// Listener keeps listening after returning error. See [`EventSource::with_error_sink`]
+    pub fn on_result<'a, F, E>(
+        &'a self,
+        mut listener: F,
+    ) -> EventFnFuture<'a, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send + 'a, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) -> Result<(), E> + Send + 'a,
+        E: Error + Send + Sync,
+    {
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if let Err(err) = listener(event, flow) {
+                self.report_error(ListenerError::Error(&err));
+            }
+        })
+    }
+
+    /// Listen events, catching panics from listener
+    ///
+    /// If listener panics, the payload is passed to error sink and `on_panic`, and listener is marked as finished.
+    /// Emission continues to next listeners.
+    pub fn on_panic_safe<'a, F, P>(
+        &'a self,
+        mut listener: F,
+        on_panic: P,
+    ) -> EventFnFuture<'a, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send + 'a, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send + 'a,
+        P: Fn(Box<dyn Any + Send>) + Send + 'a,
+    {
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| listener(event, flow))) {
+                self.report_error(ListenerError::Panic(&*payload));
+                on_panic(payload);
+                flow.set_done();
+            }
+        })
+    }
+
+    /// Listen events, catching panics from listener with their backtraces
+    ///
+    /// Works like [`EventSource::on_panic_safe`], but `on_panic` also receives [`Backtrace`] captured after listener is unwound.
+    /// Panic hook is left untouched, so backtrace leads to the listener call but does not contain frames of panic location.
+    /// Use backtrace printed by panic hook to find panic location.
+    pub fn on_panic_report<'a, F, P>(
+        &'a self,
+        mut listener: F,
+        on_panic: P,
+    ) -> EventFnFuture<'a, impl FnMut(T::Of<'_>, &mut ControlFlow) + Send + 'a, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send + 'a,
+        P: Fn(PanicReport) + Send + 'a,
+    {
+        self.on(move |event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| listener(event, flow))) {
+                let backtrace = Backtrace::capture();
+
+                self.report_error(ListenerError::Panic(&*payload));
+                on_panic(PanicReport { payload, backtrace });
+                flow.set_done();
+            }
+        })
+    }
+}
+
+#[derive(Debug)]
+/// Panic caught from listener of [`EventSource::on_panic_report`]
+pub struct PanicReport {
+    /// Panic payload
+    pub payload: Box<dyn Any + Send>,
+
+    /// Backtrace captured after listener is unwound, according to `RUST_BACKTRACE` and `RUST_LIB_BACKTRACE` environment variables
+    pub backtrace: Backtrace,
+}
+
+#[derive(Debug, Clone, Copy)]
+/// Failure of listener passed to error sink
+///
+/// See [`EventSource::with_error_sink`]
+pub enum ListenerError<'a> {
+    /// Listener panicked with payload
+    Panic(&'a (dyn Any + Send)),
+
+    /// Listener returned error
+    Error(&'a (dyn Error + Send + Sync)),
+}
+
+impl Display for ListenerError<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Panic(_) => f.write_str("listener panicked"),
+            Self::Error(err) => write!(f, "listener returned error: {err}"),
+        }
+    }
+}
+
+impl<T: ForLifetime> EventEmitter<'_, T> {
+    /// Emit event to next listener, catching panic from listener
+    ///
+    /// Panicked listener is marked as finished and cursor moves to next listener.
+    pub fn emit_next_checked(&mut self, event: T::Of<'_>) -> Result<Option<()>, ListenerPanic> {
+        match panic::catch_unwind(AssertUnwindSafe(|| self.emit_next(event))) {
+            Ok(res) => Ok(res),
+
+            Err(payload) => {
+                if let Some(item) = self.cursor.protected_mut() {
+                    item.finish(self.wakers);
+                }
+                self.cursor.move_next();
+                self.position += 1;
+
+                Err(ListenerPanic(payload))
+            }
+        }
+    }
+}
+
+/// Panic caught from listener
+pub struct ListenerPanic(pub Box<dyn Any + Send>);
+
+impl Debug for ListenerPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ListenerPanic").finish_non_exhaustive()
+    }
+}
+
+impl Display for ListenerPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("listener panicked")
+    }
+}
+
+impl std::error::Error for ListenerPanic {}
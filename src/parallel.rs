@@ -0,0 +1,66 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::ptr::NonNull;
+use std::vec::Vec;
+
+use higher_kinded_types::ForLifetime;
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{future::ListenerItem, waker::WakerSet, ControlFlow, EventSource};
+
+/// Pointer to listener item sent to rayon worker
+struct ItemPtr<T: ForLifetime>(NonNull<ListenerItem<T>>);
+
+// SAFETY: Pointers are collected from distinct nodes of locked list, so each item is accessed by one worker only
+unsafe impl<T: ForLifetime> Send for ItemPtr<T> where ListenerItem<T>: Send {}
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Emit event to every listeners in parallel on rayon thread pool, cloning it for each listener
+    ///
+    /// Listeners are called in no particular order while listener list is locked, so listeners must not use this source.
+    /// Propagation cannot be stopped, [`ControlFlow::stop_propagation`] and [`ControlFlow::ack`] are ignored.
+    /// Returns number of listeners notified.
+    pub fn emit_parallel(&self, event: T::Of<'_>) -> usize
+    where
+        for<'a> T::Of<'a>: Clone + Sync,
+    {
+        if self.is_closed() {
+            return 0;
+        }
+
+        self.with_list(|list, wakers| {
+            list.generation = list.generation.wrapping_add(1);
+            let generation = list.generation;
+
+            let mut items = Vec::with_capacity(list.len);
+            let mut cursor = list.list.cursor_front_mut();
+            while let Some(item) = cursor.protected_mut() {
+                items.push(ItemPtr(NonNull::from(item)));
+                cursor.move_next();
+            }
+            let len = items.len();
+
+            let finished = items
+                .into_par_iter()
+                .map(|item| {
+                    let mut wakers = WakerSet::new();
+                    let mut flow = ControlFlow::new(0).with_generation(generation);
+
+                    // SAFETY: Item is not accessed by other workers and listener closure pointer is valid while list is locked
+                    unsafe { (*item.0.as_ptr()).poll(event.clone(), &mut flow, &mut wakers) };
+                    wakers
+                })
+                .collect::<Vec<_>>();
+
+            for mut finished in finished {
+                wakers.append(&mut finished);
+            }
+
+            len
+        })
+    }
+}
@@ -0,0 +1,114 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    fmt::{self, Debug},
+    mem,
+    ops::Deref,
+};
+
+use alloc::collections::VecDeque;
+use parking_lot::Mutex;
+
+use crate::{__private::ForLt, EventSource};
+
+#[derive(Debug)]
+struct Queue<O> {
+    events: VecDeque<O>,
+    emitting: bool,
+}
+
+/// [`struct@EventSource`] which can be emitted from its own listeners
+///
+/// Events emitted with [`QueuedEventSource::emit`] while another emission is in progress are queued and emitted after current emission in order.
+/// Listeners of an event therefore never observe events emitted during its emission before every listener is notified.
+///
+/// Emitting directly on inner [`struct@EventSource`] from its listeners still deadlocks.
+pub struct QueuedEventSource<O> {
+    source: EventSource<ForLt!(O)>,
+    queue: Mutex<Queue<O>>,
+}
+
+impl<O> Debug for QueuedEventSource<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let queue = self.queue.lock();
+
+        f.debug_struct("QueuedEventSource")
+            .field("source", &self.source)
+            .field("queued", &queue.events.len())
+            .field("emitting", &queue.emitting)
+            .finish()
+    }
+}
+
+impl<O> Default for QueuedEventSource<O> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<O> QueuedEventSource<O> {
+    /// Create new [`QueuedEventSource`]
+    pub const fn new() -> Self {
+        Self {
+            source: EventSource::new(),
+            queue: Mutex::new(Queue {
+                events: VecDeque::new(),
+                emitting: false,
+            }),
+        }
+    }
+}
+
+impl<O: Clone> QueuedEventSource<O> {
+    /// Emit event to every listeners, cloning it for each listener
+    ///
+    /// If an emission is already in progress, event is queued and emitted by it instead.
+    pub fn emit(&self, event: O) {
+        let mut queue = self.queue.lock();
+        queue.events.push_back(event);
+        if queue.emitting {
+            return;
+        }
+        queue.emitting = true;
+        drop(queue);
+
+        // Clear emitting state even if a listener panics
+        let guard = EmittingGuard(&self.queue);
+        loop {
+            let mut queue = self.queue.lock();
+            let event = match queue.events.pop_front() {
+                Some(event) => event,
+
+                None => {
+                    // Must be cleared while holding lock, or concurrently queued event could be left behind
+                    queue.emitting = false;
+                    mem::forget(guard);
+                    return;
+                }
+            };
+            drop(queue);
+
+            self.source.emit_owned(event);
+        }
+    }
+}
+
+struct EmittingGuard<'a, O>(&'a Mutex<Queue<O>>);
+
+impl<O> Drop for EmittingGuard<'_, O> {
+    fn drop(&mut self) {
+        self.0.lock().emitting = false;
+    }
+}
+
+impl<O> Deref for QueuedEventSource<O> {
+    type Target = EventSource<ForLt!(O)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.source
+    }
+}
@@ -0,0 +1,209 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    fmt::{self, Debug},
+    future::{poll_fn, Future},
+    ops::Deref,
+    pin::pin,
+    task::Poll,
+};
+
+use alloc::{boxed::Box, collections::VecDeque, vec::Vec};
+use parking_lot::Mutex;
+
+use crate::{__private::ForLt, ControlFlow, EventSource};
+
+struct History<O> {
+    events: VecDeque<(u64, O)>,
+    capacity: usize,
+    budget: Option<ByteBudget<O>>,
+}
+
+impl<O> History<O> {
+    fn push(&mut self, generation: u64, event: O) {
+        if self.events.len() >= self.capacity {
+            self.evict();
+        }
+        if let Some(ref mut budget) = self.budget {
+            budget.used += (budget.size_of)(&event);
+        }
+        self.events.push_back((generation, event));
+
+        while self
+            .budget
+            .as_ref()
+            .is_some_and(|budget| budget.used > budget.limit)
+        {
+            self.evict();
+        }
+    }
+
+    /// Remove oldest event, returning it
+    fn evict(&mut self) -> Option<O> {
+        let (_, event) = self.events.pop_front()?;
+        if let Some(ref mut budget) = self.budget {
+            budget.used -= (budget.size_of)(&event);
+        }
+
+        Some(event)
+    }
+}
+
+struct ByteBudget<O> {
+    limit: usize,
+    used: usize,
+    size_of: Box<dyn Fn(&O) -> usize + Send>,
+}
+
+/// [`struct@EventSource`] keeping history of recent events with their generations
+///
+/// Listeners can resubscribe with [`ReplayEventSource::resubscribe`], replaying events emitted since last seen generation.
+/// Only last `capacity` events, or events within byte budget of [`ReplayEventSource::with_byte_budget`] are kept. If older events are needed, they are lost and listener observes gap between generations.
+///
+/// Events emitted directly on inner [`struct@EventSource`] are not kept in history.
+pub struct ReplayEventSource<O> {
+    source: EventSource<ForLt!(O)>,
+    history: Mutex<History<O>>,
+}
+
+impl<O> Debug for ReplayEventSource<O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let history = self.history.lock();
+
+        f.debug_struct("ReplayEventSource")
+            .field("source", &self.source)
+            .field("history", &history.events.len())
+            .field("capacity", &history.capacity)
+            .field("bytes", &history.budget.as_ref().map(|budget| budget.used))
+            .finish()
+    }
+}
+
+impl<O> ReplayEventSource<O> {
+    /// Create new [`ReplayEventSource`] keeping last `capacity` events
+    pub const fn new(capacity: usize) -> Self {
+        Self {
+            source: EventSource::new(),
+            history: Mutex::new(History {
+                events: VecDeque::new(),
+                capacity,
+                budget: None,
+            }),
+        }
+    }
+
+    /// Create new [`ReplayEventSource`] keeping recent events up to `bytes` in total size measured by `size_of`
+    ///
+    /// Oldest events are evicted until total size is within `bytes` after each emission.
+    /// `size_of` must return same size for same event. Event larger than `bytes` is not kept.
+    pub fn with_byte_budget(bytes: usize, size_of: impl Fn(&O) -> usize + Send + 'static) -> Self {
+        Self {
+            source: EventSource::new(),
+            history: Mutex::new(History {
+                events: VecDeque::new(),
+                capacity: usize::MAX,
+                budget: Some(ByteBudget {
+                    limit: bytes,
+                    used: 0,
+                    size_of: Box::new(size_of),
+                }),
+            }),
+        }
+    }
+
+    /// Take oldest event kept in history without waiting
+    ///
+    /// Taken event is removed from history, so repeated calls return events in emitted order.
+    pub fn try_next(&self) -> Option<O> {
+        self.history.lock().evict()
+    }
+
+    /// Take every events kept in history in emitted order
+    ///
+    /// History is emptied, so drained events cannot be replayed by later [`ReplayEventSource::resubscribe`]. Source keeps new events as usual.
+    pub fn drain_buffer(&self) -> Vec<O> {
+        let mut history = self.history.lock();
+        if let Some(ref mut budget) = history.budget {
+            budget.used = 0;
+        }
+
+        history.events.drain(..).map(|(_, event)| event).collect()
+    }
+}
+
+impl<O: Clone + Send> ReplayEventSource<O> {
+    /// Emit event to every listeners and keep it in history
+    ///
+    /// Event is not kept if source is closed.
+    pub fn emit(&self, event: O) {
+        let mut history = self.history.lock();
+
+        self.source.with_emitter(|mut emitter| {
+            if history.capacity > 0 {
+                history.push(emitter.generation, event.clone());
+            }
+
+            emitter.emit_cloned(event);
+        });
+    }
+
+    /// Listen events, replaying events in history with generation greater than `from_generation` first
+    ///
+    /// Replay runs on first poll, after listener is registered. No event is emitted until replay ends, so listener observes every event once in order.
+    /// Use [`ControlFlow::generation`] to remember last seen generation.
+    pub async fn resubscribe<F>(&self, from_generation: u64, listener: F)
+    where
+        F: FnMut(O, &mut ControlFlow) + Send,
+    {
+        let listener = Mutex::new(listener);
+        let mut future = pin!(self.source.on(|event, flow| {
+            if flow.done() {
+                return;
+            }
+
+            listener.lock()(event, flow);
+        }));
+
+        let mut replayed = false;
+        poll_fn(|cx| {
+            if replayed {
+                return future.as_mut().poll(cx);
+            }
+            replayed = true;
+
+            // Hold history lock while registering, so no event can be emitted between registration and replay
+            let history = self.history.lock();
+            if future.as_mut().poll(cx).is_ready() {
+                return Poll::Ready(());
+            }
+
+            let mut listener = listener.lock();
+            for (generation, event) in &history.events {
+                if *generation <= from_generation {
+                    continue;
+                }
+
+                let mut flow = ControlFlow::new(0).with_generation(*generation);
+                listener(event.clone(), &mut flow);
+                if flow.done() {
+                    return Poll::Ready(());
+                }
+            }
+
+            Poll::Pending
+        })
+        .await;
+    }
+}
+
+impl<O> Deref for ReplayEventSource<O> {
+    type Target = EventSource<ForLt!(O)>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.source
+    }
+}
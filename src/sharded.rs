@@ -0,0 +1,113 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    fmt::{self, Debug},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use higher_kinded_types::ForLifetime;
+
+use crate::{ControlFlow, EventEmitter, EventFnFuture, EventSource};
+
+#[macro_export]
+/// Higher kinded type helper for [`ShardedEventSource`]
+macro_rules! ShardedEventSource {
+    ($n: expr; $($ty: tt)*) => {
+        $crate::ShardedEventSource<$crate::__private::ForLt!($($ty)*), $n>
+    };
+}
+
+/// Event source spreading listeners across `SHARDS` independent listener lists
+///
+/// Listeners are assigned to shards in turn, and each shard has its own lock, so registrations and emissions contend less.
+/// Assignment is round robin rather than hashed, so listener count stays balanced across shards but listeners registered together do not share shard.
+/// Emission visits shards one by one, so listeners of different shards may observe concurrent emissions in different order.
+/// Order between listeners is only kept within a shard.
+pub struct ShardedEventSource<T: ForLifetime, const SHARDS: usize> {
+    shards: [EventSource<T>; SHARDS],
+    next: AtomicUsize,
+}
+
+impl<T: ForLifetime, const SHARDS: usize> Debug for ShardedEventSource<T, SHARDS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ShardedEventSource")
+            .field("shards", &self.shards)
+            .finish()
+    }
+}
+
+impl<T: ForLifetime, const SHARDS: usize> Default for ShardedEventSource<T, SHARDS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: ForLifetime, const SHARDS: usize> ShardedEventSource<T, SHARDS> {
+    /// Create new [`ShardedEventSource`]
+    ///
+    /// # Panics
+    /// Panics if `SHARDS` is zero
+    pub const fn new() -> Self {
+        assert!(SHARDS > 0, "SHARDS must be greater than zero");
+
+        Self {
+            shards: [const { EventSource::new() }; SHARDS],
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    /// Shards of this source
+    pub const fn shards(&self) -> &[EventSource<T>; SHARDS] {
+        &self.shards
+    }
+
+    /// Close every shards
+    pub fn close(&self) {
+        self.shards.iter().for_each(EventSource::close);
+    }
+
+    /// Number of listeners registered across every shards
+    pub fn listener_count(&self) -> usize {
+        self.shards.iter().map(EventSource::listener_count).sum()
+    }
+
+    /// Call `emit_fn` with [`EventEmitter`] of each shard in order
+    ///
+    /// Only one shard is locked at a time.
+    pub fn with_emitter_each(&self, mut emit_fn: impl FnMut(EventEmitter<T>)) {
+        for shard in &self.shards {
+            shard.with_emitter(&mut emit_fn);
+        }
+    }
+
+    /// Emit event to every listeners of every shards, cloning it for each listener
+    ///
+    /// Returns number of listeners notified.
+    pub fn emit_owned(&self, event: T::Of<'_>) -> usize
+    where
+        for<'a> T::Of<'a>: Clone,
+    {
+        let (last, rest) = self.shards.split_last().unwrap();
+
+        rest.iter()
+            .map(|shard| shard.emit_owned(event.clone()))
+            .sum::<usize>()
+            + last.emit_owned(event)
+    }
+
+    /// Listen events on next shard in round robin order
+    ///
+    /// See [`EventSource::on`]
+    pub fn on<F>(&self, listener: F) -> EventFnFuture<'_, F, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % SHARDS;
+
+        self.shards[index].on(listener)
+    }
+}
@@ -0,0 +1,60 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::fmt::{self, Debug};
+
+use crate::{EventEmitter, EventSource};
+
+/// Emitter sharing one computed value with every listeners
+///
+/// Created with [`EventSource::with_emitter_shared`].
+pub struct SharedEmitter<'a, V> {
+    emitter: EventEmitter<'a, crate::__private::ForLt!(&'_ V)>,
+    value: V,
+}
+
+impl<V: Debug> Debug for SharedEmitter<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedEmitter")
+            .field("value", &self.value)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<V> SharedEmitter<'_, V> {
+    /// Shared value
+    pub const fn value(&self) -> &V {
+        &self.value
+    }
+
+    /// Emit shared value to next listener
+    pub fn emit_next(&mut self) -> Option<()> {
+        self.emitter.emit_next(&self.value)
+    }
+
+    /// Emit shared value to every remaining listeners
+    pub fn emit_all(&mut self) {
+        while self.emit_next().is_some() {}
+    }
+}
+
+impl<V> EventSource<crate::__private::ForLt!(&'_ V)> {
+    /// Create [`SharedEmitter`] with value from `compute`
+    ///
+    /// `compute` is called once per emission, and is not called if source is closed.
+    pub fn with_emitter_shared(
+        &self,
+        compute: impl FnOnce() -> V,
+        emit_fn: impl FnOnce(SharedEmitter<V>),
+    ) {
+        self.with_emitter(|emitter| {
+            emit_fn(SharedEmitter {
+                emitter,
+                value: compute(),
+            })
+        });
+    }
+}
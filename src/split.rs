@@ -0,0 +1,93 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use alloc::sync::Arc;
+use higher_kinded_types::ForLifetime;
+
+use crate::{ControlFlow, EventEmitter, EventFnFuture, EventSource, OwnedEventFnFuture};
+
+/// Emitting half of [`struct@EventSource`] created with [`EventSource::split`]
+#[derive(Debug)]
+pub struct Emitter<T: ForLifetime> {
+    source: Arc<EventSource<T>>,
+}
+
+impl<T: ForLifetime> Clone for Emitter<T> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+        }
+    }
+}
+
+impl<T: ForLifetime> Emitter<T> {
+    /// See [`EventSource::with_emitter`]
+    pub fn with_emitter(&self, emit_fn: impl FnOnce(EventEmitter<T>)) {
+        self.source.with_emitter(emit_fn);
+    }
+}
+
+/// Listening half of [`struct@EventSource`] created with [`EventSource::split`]
+///
+/// It cannot emit events.
+/// ```compile_fail
+/// use event_source::{emit, EventSource};
+/// use std::sync::Arc;
+///
+/// let (_, subscriber) = Arc::new(<EventSource!(i32)>::new()).split();
+/// emit!(subscriber, 0);
+/// ```
+#[derive(Debug)]
+pub struct Subscriber<T: ForLifetime> {
+    source: Arc<EventSource<T>>,
+}
+
+impl<T: ForLifetime> Clone for Subscriber<T> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+        }
+    }
+}
+
+impl<T: ForLifetime> Subscriber<T> {
+    /// See [`EventSource::on`]
+    pub fn on<F>(&self, listener: F) -> EventFnFuture<'_, F, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        self.source.on(listener)
+    }
+
+    /// See [`EventSource::on_owned`]
+    pub fn on_owned<F>(&self, listener: F) -> OwnedEventFnFuture<F, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send,
+    {
+        self.source.on_owned(listener)
+    }
+
+    /// See [`EventSource::once`]
+    pub async fn once<F, R>(&self, listener: F) -> Option<R>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) -> Option<R> + Send,
+        R: Send,
+    {
+        self.source.once(listener).await
+    }
+}
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Split shared [`struct@EventSource`] into emitting and listening halves
+    pub fn split(self: Arc<Self>) -> (Emitter<T>, Subscriber<T>) {
+        (
+            Emitter {
+                source: self.clone(),
+            },
+            Subscriber { source: self },
+        )
+    }
+}
@@ -0,0 +1,137 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use core::{
+    fmt::{self, Debug},
+    pin::Pin,
+    sync::atomic::Ordering,
+};
+
+use alloc::{boxed::Box, vec::Vec};
+use higher_kinded_types::ForLifetime;
+use unique::Unique;
+
+use crate::{
+    future::{DynClosure, ListenerItem},
+    types::Node,
+    ControlFlow, EventSource,
+};
+
+#[must_use = "subscription is cancelled once dropped"]
+/// Synchronous listener registration created with [`EventSource::subscribe`]
+///
+/// Listener is removed when dropped.
+pub struct Subscription<'a, T: ForLifetime> {
+    source: &'a EventSource<T>,
+    node: Pin<Box<Node<T>>>,
+    closure: Unique<DynClosure<'a, T>>,
+}
+
+impl<'a, T: ForLifetime> Subscription<'a, T> {
+    fn new(source: &'a EventSource<T>, closure: Box<DynClosure<'a, T>>) -> Self {
+        let closure = Unique::new(Box::into_raw(closure)).unwrap();
+        let mut node = Box::pin(pin_list::Node::new());
+
+        let mut list = source.lock_list();
+        if !source.closed.load(Ordering::Relaxed) {
+            list.insert(node.as_mut(), ListenerItem::new(closure));
+        }
+        drop(list);
+
+        Self {
+            source,
+            node,
+            closure,
+        }
+    }
+}
+
+impl<T: ForLifetime> Debug for Subscription<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Subscription")
+            .field("source", &self.source)
+            .field("node", &self.node)
+            .finish()
+    }
+}
+
+impl<T: ForLifetime> Drop for Subscription<'_, T> {
+    fn drop(&mut self) {
+        if let Some(node) = self.node.as_mut().initialized_mut() {
+            let item = self.source.lock_list().reset(node);
+            drop(item);
+        }
+
+        // SAFETY: Closure is allocated by Box in Subscription::new and no longer referenced by list
+        drop(unsafe { Box::from_raw(self.closure.as_ptr()) });
+    }
+}
+
+impl<T: ForLifetime> EventSource<T> {
+    /// Listen events synchronously until returned [`Subscription`] is dropped
+    ///
+    /// Listener must be `'static` as [`Subscription`] can be leaked without being dropped.
+    /// Use [`EventSource::scope`] to subscribe borrowing listeners.
+    pub fn subscribe<F>(&self, listener: F) -> Subscription<'_, T>
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send + 'static,
+    {
+        Subscription::new(self, Box::new(listener))
+    }
+
+    /// Listen only one event synchronously
+    ///
+    /// Listener is marked as finished after it is called. Dropping returned [`Subscription`] before emission cancels it.
+    pub fn subscribe_once<F>(&self, listener: F) -> Subscription<'_, T>
+    where
+        F: FnOnce(T::Of<'_>) + Send + 'static,
+    {
+        let mut listener = Some(listener);
+
+        self.subscribe(move |event, flow| {
+            if let Some(listener) = listener.take() {
+                listener(event);
+                flow.set_done();
+            }
+        })
+    }
+
+    /// Run `f` with [`SubscriptionScope`], removing every listeners subscribed on the scope once `f` returns
+    ///
+    /// Scope cannot be leaked, so its listeners can borrow from outside of the scope.
+    pub fn scope<'a, R>(&'a self, f: impl FnOnce(&mut SubscriptionScope<'a, T>) -> R) -> R {
+        f(&mut SubscriptionScope {
+            source: self,
+            subscriptions: Vec::new(),
+        })
+    }
+}
+
+/// Scope of listeners created with [`EventSource::scope`]
+pub struct SubscriptionScope<'a, T: ForLifetime> {
+    source: &'a EventSource<T>,
+    subscriptions: Vec<Subscription<'a, T>>,
+}
+
+impl<'a, T: ForLifetime> SubscriptionScope<'a, T> {
+    /// Listen events synchronously until the scope ends
+    pub fn subscribe<F>(&mut self, listener: F)
+    where
+        F: FnMut(T::Of<'_>, &mut ControlFlow) + Send + 'a,
+    {
+        self.subscriptions
+            .push(Subscription::new(self.source, Box::new(listener)));
+    }
+}
+
+impl<T: ForLifetime> Debug for SubscriptionScope<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubscriptionScope")
+            .field("source", &self.source)
+            .field("subscriptions", &self.subscriptions)
+            .finish()
+    }
+}
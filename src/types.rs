@@ -4,6 +4,17 @@
  * Copyright (c) storycraft. Licensed under the MIT Licence.
  */
 
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    pin::Pin,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use higher_kinded_types::ForLifetime;
+use pin_list::{InitializedNode, NodeData};
+
 use crate::future::ListenerItem;
 
 pub(crate) type NodeTypes<T> = dyn pin_list::Types<
@@ -16,3 +27,117 @@ pub(crate) type NodeTypes<T> = dyn pin_list::Types<
 pub(crate) type PinList<T> = pin_list::PinList<NodeTypes<T>>;
 
 pub(crate) type Node<T> = pin_list::Node<NodeTypes<T>>;
+
+/// Listener list with its length tracked
+pub(crate) struct ListenerList<T: ForLifetime> {
+    pub list: PinList<T>,
+    pub len: usize,
+
+    /// Incremented every time a listener is registered or removed
+    pub version: usize,
+
+    /// Incremented every emission pass
+    pub generation: u64,
+
+    #[cfg(feature = "alloc")]
+    pub on_changed: Option<Box<dyn Fn(usize) + Send>>,
+}
+
+impl<T: ForLifetime> ListenerList<T> {
+    pub const fn new(list: PinList<T>) -> Self {
+        Self {
+            list,
+            len: 0,
+            version: 0,
+            generation: 0,
+
+            #[cfg(feature = "alloc")]
+            on_changed: None,
+        }
+    }
+
+    /// Bump version and notify changed listener count
+    fn changed(&mut self) {
+        self.version = self.version.wrapping_add(1);
+
+        #[cfg(feature = "alloc")]
+        if let Some(ref on_changed) = self.on_changed {
+            on_changed(self.len);
+        }
+    }
+
+    /// Insert listener before first listener with greater key, or at the back if it has no key
+    pub fn insert<'node>(
+        &mut self,
+        node: Pin<&'node mut Node<T>>,
+        item: ListenerItem<T>,
+    ) -> Pin<&'node mut InitializedNode<'node, NodeTypes<T>>> {
+        self.len += 1;
+        self.changed();
+
+        let mut cursor = self.list.cursor_ghost_mut();
+        if let Some(key) = item.key() {
+            cursor.move_next();
+
+            while let Some(current) = cursor.protected() {
+                if let Some(Ordering::Greater) =
+                    current.key().and_then(|current| current.partial_cmp(key))
+                {
+                    break;
+                }
+
+                cursor.move_next();
+            }
+        }
+
+        cursor.insert_before(node, item, ())
+    }
+
+    /// Unlink first listener matching `f`, returning its item
+    pub fn remove(
+        &mut self,
+        mut f: impl FnMut(&ListenerItem<T>) -> bool,
+    ) -> Option<ListenerItem<T>> {
+        let mut cursor = self.list.cursor_front_mut();
+        let item = loop {
+            if f(cursor.protected()?) {
+                break cursor.remove_current(()).ok()?;
+            }
+
+            cursor.move_next();
+        };
+
+        self.len -= 1;
+        self.changed();
+
+        Some(item)
+    }
+
+    /// Remove listener if it is linked, returning its item
+    pub fn reset<'node>(
+        &mut self,
+        node: Pin<&'node mut InitializedNode<'node, NodeTypes<T>>>,
+    ) -> Option<ListenerItem<T>> {
+        match node.reset(&mut self.list) {
+            (NodeData::Linked(item), _) => {
+                self.len -= 1;
+                self.changed();
+
+                Some(item)
+            }
+
+            _ => None,
+        }
+    }
+}
+
+impl<T: ForLifetime> Debug for ListenerList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListenerList")
+            .field("list", &self.list)
+            .field("len", &self.len)
+            .field("version", &self.version)
+            .field("generation", &self.generation)
+            .finish()
+    }
+}
@@ -0,0 +1,116 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::task::Waker;
+
+#[cfg(not(feature = "alloc"))]
+/// Number of wakers [`WakerSet`] can hold without `alloc` feature
+const INLINE_WAKERS: usize = 8;
+
+#[derive(Debug, Default)]
+/// Wakers collected during emission
+///
+/// Only consecutive wakers of same task are merged using [`Waker::will_wake`], so listeners of one task registered together wake it once.
+/// Wakers are not deduplicated otherwise. A task whose listeners are interleaved with listeners of other tasks is woken once per run.
+/// Wakers are woken once dropped, so it must be dropped after lock guard.
+/// Without `alloc` feature, it holds at most [`INLINE_WAKERS`] wakers and rejects more. Rejected wakers are deferred in listeners, see [`crate::future::ListenerItem::take_deferred`].
+pub(crate) struct WakerSet {
+    #[cfg(feature = "alloc")]
+    wakers: Vec<Waker>,
+
+    #[cfg(not(feature = "alloc"))]
+    wakers: [Option<Waker>; INLINE_WAKERS],
+
+    #[cfg(not(feature = "alloc"))]
+    len: usize,
+
+    #[cfg(not(feature = "alloc"))]
+    overflowed: bool,
+}
+
+impl WakerSet {
+    pub const fn new() -> Self {
+        Self {
+            #[cfg(feature = "alloc")]
+            wakers: Vec::new(),
+
+            #[cfg(not(feature = "alloc"))]
+            wakers: [const { None }; INLINE_WAKERS],
+
+            #[cfg(not(feature = "alloc"))]
+            len: 0,
+
+            #[cfg(not(feature = "alloc"))]
+            overflowed: false,
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn insert(&mut self, waker: Waker) {
+        if !self
+            .wakers
+            .last()
+            .is_some_and(|last| last.will_wake(&waker))
+        {
+            self.wakers.push(waker);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    /// Move every wakers of `other` into this set
+    pub fn append(&mut self, other: &mut Self) {
+        self.wakers.append(&mut other.wakers);
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    /// Insert waker, returning it back if set is full
+    pub fn try_insert(&mut self, waker: Waker) -> Result<(), Waker> {
+        let last = self
+            .len
+            .checked_sub(1)
+            .and_then(|last| self.wakers[last].as_ref());
+        if last.is_some_and(|last| last.will_wake(&waker)) {
+            return Ok(());
+        }
+
+        if self.len == INLINE_WAKERS {
+            self.overflowed = true;
+            return Err(waker);
+        }
+
+        self.wakers[self.len] = Some(waker);
+        self.len += 1;
+        Ok(())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    /// Check if any waker is rejected since created
+    pub const fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    pub fn wake_all(&mut self) {
+        #[cfg(feature = "alloc")]
+        self.wakers.drain(..).for_each(Waker::wake);
+
+        #[cfg(not(feature = "alloc"))]
+        {
+            self.wakers[..self.len]
+                .iter_mut()
+                .filter_map(Option::take)
+                .for_each(Waker::wake);
+            self.len = 0;
+        }
+    }
+}
+
+impl Drop for WakerSet {
+    fn drop(&mut self) {
+        self.wake_all();
+    }
+}
@@ -0,0 +1,853 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use core::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use common::{poll_once, WakeCounter};
+use event_source::{
+    __private::ForLt, emit, on_variant, CancelToken, EventSource, RaceListener, RetryOutcome,
+    StateStep,
+};
+
+type Listener = RaceListener<'static, ForLt!(&i32), &'static str>;
+type Source = EventSource!(i32);
+
+#[test]
+fn race_returns_first_match() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let listeners: Vec<Listener> = vec![
+        Box::new(|&event, _| (event == 2).then_some("first")),
+        Box::new(|&event, _| (event == 1).then_some("second")),
+    ];
+    let mut future = pin!(source.race(listeners));
+    assert!(poll_once(future.as_mut()).is_pending());
+    assert_eq!(source.listener_count(), 2);
+
+    emit!(source, &1);
+    assert_eq!(poll_once(future.as_mut()), Poll::Ready(Some("second")));
+    assert_eq!(source.listener_count(), 0);
+}
+
+#[test]
+fn race_without_listeners() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let mut future = pin!(source.race::<()>(Vec::new()));
+    assert_eq!(poll_once(future.as_mut()), Poll::Ready(None));
+}
+
+#[test]
+fn cancel_token_wakes_listeners() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let token = CancelToken::new();
+    let called = AtomicUsize::new(0);
+
+    let counters = [
+        Arc::new(WakeCounter::default()),
+        Arc::new(WakeCounter::default()),
+    ];
+    let wakers = counters.clone().map(Waker::from);
+
+    let mut first = pin!(source.on_cancellable(&token, |_, _| {
+        called.fetch_add(1, Ordering::Relaxed);
+    }));
+    let mut second = pin!(source.on_cancellable(&token, |_, _| {
+        called.fetch_add(1, Ordering::Relaxed);
+    }));
+    assert!(first
+        .as_mut()
+        .poll(&mut Context::from_waker(&wakers[0]))
+        .is_pending());
+    assert!(second
+        .as_mut()
+        .poll(&mut Context::from_waker(&wakers[1]))
+        .is_pending());
+
+    emit!(source, &1);
+    assert_eq!(called.load(Ordering::Relaxed), 2);
+
+    token.cancel();
+    assert!(token.is_cancelled());
+    for counter in &counters {
+        assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+    }
+    assert!(poll_once(first.as_mut()).is_ready());
+    assert!(poll_once(second.as_mut()).is_ready());
+
+    emit!(source, &2);
+    assert_eq!(called.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn into_delivers_owned_values() {
+    let source: EventSource!(&str) = EventSource::new();
+    let mut received = Vec::<String>::new();
+
+    {
+        let mut future = pin!(source.on_into(|event: String, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        let event = String::from("owned");
+        emit!(source, &event);
+    }
+
+    assert_eq!(received, ["owned"]);
+}
+
+#[test]
+fn while_finishes_on_first_rejected_event() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future =
+            pin!(source.on_while(|&&event| event != 0, |&event, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in [1, 2, 3, 0] {
+            emit!(source, &event);
+        }
+        assert!(poll_once(future.as_mut()).is_ready());
+        emit!(source, &4);
+    }
+
+    assert_eq!(received, [1, 2, 3]);
+}
+
+#[test]
+fn coalesce_delivers_every_n_events() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_coalesce(100, |count, _| received.push(count)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in 0..250 {
+            emit!(source, &event);
+        }
+    }
+
+    assert_eq!(received, [100, 100]);
+}
+
+#[test]
+fn coalesce_stops_once_finished() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mut received = 0;
+
+    {
+        let mut future = pin!(source.on_coalesce(2, |_, flow| {
+            received += 1;
+            flow.set_done();
+        }));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in 0..8 {
+            emit!(source, &event);
+        }
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert_eq!(received, 1);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn results_sent_to_receiver() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let (future, receiver) = source.on_results(|&event| Some(event * event));
+    let mut future = pin!(future);
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    for event in 1..=3 {
+        emit!(source, &event);
+    }
+
+    assert_eq!(receiver.try_iter().collect::<Vec<_>>(), [1, 4, 9]);
+}
+
+#[test]
+fn ctx_read_on_each_event() {
+    struct Config {
+        scale: i32,
+    }
+
+    let source: EventSource!(&i32) = EventSource::new();
+    let config = Config { scale: 10 };
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_with_ctx(&config, |config, &event, _| received
+            .push(event * config.scale)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1);
+        emit!(source, &2);
+    }
+
+    assert_eq!(received, [10, 20]);
+}
+
+#[test]
+fn each_match_rearms_after_match() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mut matched = Vec::new();
+
+    {
+        let mut future = pin!(source.on_each_match(
+            |&event| (event % 2 == 0).then_some(event),
+            |event| matched.push(event)
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in 1..=4 {
+            emit!(source, &event);
+        }
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    assert_eq!(matched, [2, 4]);
+}
+
+#[test]
+fn each_match_stops_once_finished() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mut matched = Vec::new();
+
+    {
+        let mut future =
+            pin!(source.on_each_match(|&event| Some(event), |event| matched.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1);
+        source.for_each_listener(|item| item.set_done());
+        emit!(source, &2);
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert_eq!(matched, [1]);
+}
+
+#[test]
+fn as_listens_downcasted_events() {
+    let source: EventSource!(&dyn core::any::Any) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_as::<i32, _>(|&event, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1_i32);
+        emit!(source, &"text");
+        emit!(source, &2_i32);
+    }
+
+    assert_eq!(received, [1, 2]);
+}
+
+#[test]
+fn counted_listener_counts_calls() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let (future, count) = source.on_counted(|_, _| {});
+    let mut future = pin!(future);
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    for event in 0..4 {
+        emit!(source, &event);
+    }
+
+    assert_eq!(count.load(Ordering::Relaxed), 4);
+}
+
+#[test]
+fn first_then_rest_split_events() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let first = std::sync::Mutex::new(Vec::new());
+    let rest = std::sync::Mutex::new(Vec::new());
+
+    {
+        let mut future = pin!(source.on_first_then(
+            |&event| first.lock().unwrap().push(event),
+            |&event, _| rest.lock().unwrap().push(event)
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in 1..=3 {
+            emit!(source, &event);
+        }
+    }
+
+    assert_eq!(first.into_inner().unwrap(), [1]);
+    assert_eq!(rest.into_inner().unwrap(), [2, 3]);
+}
+
+#[test]
+fn first_then_rest_stops_once_finished() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mut rest = Vec::new();
+
+    {
+        let mut future = pin!(source.on_first_then(
+            |_| {},
+            |&event, flow| {
+                rest.push(event);
+                flow.set_done();
+            }
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in 1..=4 {
+            emit!(source, &event);
+        }
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert_eq!(rest, [2]);
+}
+
+#[test]
+fn cleanup_runs_once_on_completion_and_drop() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let cleaned = AtomicUsize::new(0);
+
+    {
+        let mut future = pin!(source.on_with_cleanup(
+            |_, flow| flow.set_done(),
+            || {
+                cleaned.fetch_add(1, Ordering::Relaxed);
+            }
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1);
+        assert!(poll_once(future.as_mut()).is_ready());
+        assert_eq!(cleaned.load(Ordering::Relaxed), 1);
+    }
+    assert_eq!(cleaned.load(Ordering::Relaxed), 1);
+
+    {
+        let mut future = pin!(source.on_with_cleanup(
+            |_, _| {},
+            || {
+                cleaned.fetch_add(1, Ordering::Relaxed);
+            }
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+    assert_eq!(cleaned.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn join_all_waits_for_every_source() {
+    let sources: [Source; 3] = [EventSource::new(), EventSource::new(), EventSource::new()];
+    let [first, second, third] = &sources;
+
+    let refs = [first, second, third];
+    let mut join = pin!(EventSource::join_all(&refs));
+    assert!(poll_once(join.as_mut()).is_pending());
+
+    first.emit_owned(1);
+    second.emit_owned(2);
+    assert!(poll_once(join.as_mut()).is_pending());
+
+    third.emit_owned(3);
+    assert_eq!(poll_once(join.as_mut()), Poll::Ready(Some(vec![1, 2, 3])));
+    assert!(sources.iter().all(|source| source.listener_count() == 0));
+}
+
+#[test]
+fn budgeted_wakes_task_after_budget() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let counter = Arc::new(WakeCounter::default());
+    let waker = Waker::from(counter.clone());
+
+    let mut future = pin!(source.on_budgeted(3, |_, _| {}));
+    assert!(future
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker))
+        .is_pending());
+
+    emit!(source, &1);
+    emit!(source, &2);
+    assert_eq!(counter.0.load(Ordering::Relaxed), 0);
+
+    emit!(source, &3);
+    assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn expiring_listener_stops_at_deadline() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let now = AtomicUsize::new(0);
+    let mut received = Vec::new();
+
+    {
+        let clock = || now.load(Ordering::Relaxed);
+        let mut future = pin!(source.on_expiring(clock, 10, |&event, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1);
+        now.store(10, Ordering::Relaxed);
+        emit!(source, &2);
+        assert!(poll_once(future.as_mut()).is_ready());
+        emit!(source, &3);
+    }
+
+    assert_eq!(received, [1]);
+}
+
+#[test]
+fn weak_listener_finishes_once_state_dropped() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let state = Arc::new(AtomicUsize::new(0));
+
+    let mut future = pin!(source.on_weak(Arc::downgrade(&state), |state, &event, _| {
+        state.fetch_add(event as usize, Ordering::Relaxed);
+    }));
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    emit!(source, &1);
+    assert_eq!(state.load(Ordering::Relaxed), 1);
+
+    drop(state);
+    emit!(source, &2);
+    assert!(poll_once(future.as_mut()).is_ready());
+}
+
+#[test]
+fn idle_timeout_resolves_after_quiet_delay() {
+    use std::sync::atomic::AtomicBool;
+
+    let source: EventSource!(&i32) = EventSource::new();
+    let elapsed = AtomicBool::new(false);
+    let delays = AtomicUsize::new(0);
+    let mut received = Vec::new();
+
+    {
+        let idle = || {
+            delays.fetch_add(1, Ordering::Relaxed);
+            core::future::poll_fn(|_| match elapsed.load(Ordering::Relaxed) {
+                true => Poll::Ready(()),
+                false => Poll::Pending,
+            })
+        };
+        let mut future = pin!(source.on_idle_timeout(idle, |&event, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1);
+        assert!(poll_once(future.as_mut()).is_pending());
+        assert_eq!(delays.load(Ordering::Relaxed), 2);
+
+        elapsed.store(true, Ordering::Relaxed);
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert_eq!(received, [1]);
+}
+
+#[test]
+fn mapped_view_driven_by_base_source() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mapped = source.map(|&event: &i32| match event {
+        0..=9 => "short",
+        _ => "long",
+    });
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(mapped.on(|category, _| received.push(category)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &3);
+        emit!(source, &42);
+    }
+
+    assert_eq!(received, ["short", "long"]);
+}
+
+#[test]
+fn retrying_listener_called_until_done() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mut outcomes = Vec::new();
+
+    {
+        let mut future = pin!(source.on_retrying(3, |_, _| {
+            let outcome = match outcomes.len() {
+                0 | 1 => RetryOutcome::Retry,
+                _ => RetryOutcome::Done,
+            };
+            outcomes.push(outcome);
+            outcome
+        }));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1);
+    }
+
+    assert_eq!(
+        outcomes,
+        [RetryOutcome::Retry, RetryOutcome::Retry, RetryOutcome::Done]
+    );
+}
+
+#[test]
+fn filter_map_skips_unparsed_events() {
+    let source: EventSource!(&str) = EventSource::new();
+    let mut parsed = Vec::new();
+
+    {
+        let mut future = pin!(source.on_each_match(
+            |event| event.parse::<i32>().ok(),
+            |value| parsed.push(value)
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in ["1", "two", "3"] {
+            emit!(source, event);
+        }
+    }
+
+    assert_eq!(parsed, [1, 3]);
+}
+
+#[test]
+fn custom_waker_woken_instead_of_task() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let custom = Arc::new(WakeCounter::default());
+    let task = Arc::new(WakeCounter::default());
+    let task_waker = Waker::from(task.clone());
+
+    let mut future =
+        pin!(source.on_with_waker(Waker::from(custom.clone()), |_, flow| flow.set_done()));
+    assert!(future
+        .as_mut()
+        .poll(&mut Context::from_waker(&task_waker))
+        .is_pending());
+
+    emit!(source, &1);
+    assert_eq!(custom.0.load(Ordering::Relaxed), 1);
+    assert_eq!(task.0.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn returning_outputs_collected_in_order() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let mut futures = [0, 1, 2].map(|index| Box::pin(source.on_returning(move |_, _| index * 2)));
+    for future in &mut futures {
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    let outputs =
+        source.with_emitter_collect::<i32>(|emitter| while emitter.emit_next(&1).is_some() {});
+    assert_eq!(outputs, [0, 2, 4]);
+}
+
+#[cfg(all(feature = "std", debug_assertions))]
+#[test]
+fn pinned_thread_listener_asserts_other_thread() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_pinned_thread(|&event, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1);
+        std::thread::scope(|scope| {
+            assert!(scope.spawn(|| emit!(source, &2)).join().is_err());
+        });
+    }
+
+    assert_eq!(received, [1]);
+}
+
+#[test]
+fn intercepting_listener_passes_other_events() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mut intercepted = Vec::new();
+    let mut passed = Vec::new();
+
+    {
+        let mut interceptor = pin!(source.on_intercepting(
+            |&&event| event % 2 == 1,
+            |&event, _| intercepted.push(event)
+        ));
+        let mut downstream = pin!(source.on(|&event, _| passed.push(event)));
+        assert!(poll_once(interceptor.as_mut()).is_pending());
+        assert!(poll_once(downstream.as_mut()).is_pending());
+
+        for event in 1..=4 {
+            emit!(source, &event);
+        }
+    }
+
+    assert_eq!(intercepted, [1, 3]);
+    assert_eq!(passed, [2, 4]);
+}
+
+#[test]
+fn rate_counts_events_within_window() {
+    use core::time::Duration;
+
+    let source: EventSource!(&i32) = EventSource::new();
+    let now = AtomicUsize::new(0);
+    let mut rates = Vec::new();
+
+    {
+        let clock = || Duration::from_millis(now.load(Ordering::Relaxed) as u64);
+        let mut future = pin!(source.on_with_rate(
+            clock,
+            Duration::from_millis(10),
+            |rate, _, _| rates.push(rate)
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for time in [0, 5, 9, 10, 25] {
+            now.store(time, Ordering::Relaxed);
+            emit!(source, &1);
+        }
+    }
+
+    assert_eq!(rates, [1, 2, 3, 3, 1]);
+}
+
+#[test]
+fn distinct_by_skips_repeated_keys() {
+    let source: EventSource!(&(i32, char)) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future =
+            pin!(source.on_distinct_by(|&&(id, _)| id, |&(_, value), _| received.push(value)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in [(1, 'a'), (1, 'b'), (2, 'c')] {
+            emit!(source, &event);
+        }
+    }
+
+    assert_eq!(received, ['a', 'c']);
+}
+
+#[test]
+fn timed_reports_listener_duration() {
+    use core::time::Duration;
+
+    let source: EventSource!(&i32) = EventSource::new();
+    let now = AtomicUsize::new(0);
+    let mut reports = Vec::new();
+
+    {
+        let mut future = pin!(source.on_timed(
+            || Duration::from_millis(now.load(Ordering::Relaxed) as u64),
+            |&event, _| {
+                now.fetch_add(event as usize, Ordering::Relaxed);
+            },
+            |duration| reports.push(duration),
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &5);
+        emit!(source, &2);
+    }
+
+    assert_eq!(
+        reports,
+        [Duration::from_millis(5), Duration::from_millis(2)]
+    );
+}
+
+#[test]
+fn async_cleanup_awaited_after_completion() {
+    use core::future::poll_fn;
+
+    let source: EventSource!(&i32) = EventSource::new();
+    let cleaned = AtomicUsize::new(0);
+    let cleaned_ref = &cleaned;
+
+    let mut future = pin!(source.on_with_async_cleanup(
+        |_, flow| flow.set_done(),
+        || {
+            let mut flushed = false;
+            poll_fn(move |_| {
+                if !flushed {
+                    flushed = true;
+                    return Poll::Pending;
+                }
+
+                cleaned_ref.fetch_add(1, Ordering::Relaxed);
+                Poll::Ready(())
+            })
+        }
+    ));
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    emit!(source, &1);
+    assert!(poll_once(future.as_mut()).is_pending());
+    assert_eq!(cleaned.load(Ordering::Relaxed), 0);
+
+    assert!(poll_once(future.as_mut()).is_ready());
+    assert_eq!(cleaned.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn until_predicate_finishes_once_flag_set() {
+    use std::sync::atomic::AtomicBool;
+
+    let source: EventSource!(&i32) = EventSource::new();
+    let flag = AtomicBool::new(false);
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_until_predicate(
+            || flag.load(Ordering::Relaxed),
+            |&event, _| received.push(event)
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1);
+        emit!(source, &2);
+        flag.store(true, Ordering::Relaxed);
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &3);
+        assert!(poll_once(future.as_mut()).is_ready());
+        emit!(source, &4);
+    }
+
+    assert_eq!(received, [1, 2, 3]);
+}
+
+#[test]
+fn state_machine_completes_handshake() {
+    #[derive(Debug, Clone, Copy)]
+    enum Handshake {
+        Idle,
+        SynReceived(u32),
+    }
+
+    let source: EventSource!((&str, u32)) = EventSource::new();
+
+    let mut future = pin!(source.on_state_machine(Handshake::Idle, |state, event| {
+        match (state, event) {
+            (Handshake::Idle, ("syn", seq)) => StateStep::Continue(Handshake::SynReceived(seq)),
+            (Handshake::SynReceived(seq), ("ack", ack)) if ack == seq + 1 => StateStep::Done(ack),
+            (state, _) => StateStep::Continue(state),
+        }
+    }));
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    source.emit_owned(("ack", 1));
+    source.emit_owned(("syn", 7));
+    source.emit_owned(("ack", 9));
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    source.emit_owned(("ack", 8));
+    assert_eq!(poll_once(future.as_mut()), Poll::Ready(Some(8)));
+}
+
+#[test]
+fn quota_passes_event_after_consecutive_stops() {
+    let source: Source = EventSource::new();
+    let mut greedy = Vec::new();
+    let mut downstream = Vec::new();
+
+    {
+        let mut future_greedy = pin!(source.on_with_quota(2, |event, flow| {
+            greedy.push(event);
+            flow.stop_propagation();
+        }));
+        let mut future_downstream = pin!(source.on(|event, _| downstream.push(event)));
+        assert!(poll_once(future_greedy.as_mut()).is_pending());
+        assert!(poll_once(future_downstream.as_mut()).is_pending());
+
+        for event in 1..=4 {
+            source.emit_owned(event);
+        }
+    }
+
+    assert_eq!(greedy, [1, 2, 3, 4]);
+    assert_eq!(downstream, [3]);
+}
+
+#[test]
+fn merge_ordered_delivers_in_key_order() {
+    let first: Source = EventSource::new();
+    let second: Source = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let sources = [&first, &second];
+        let mut future = pin!(EventSource::merge_ordered(
+            &sources,
+            |&key| key,
+            1,
+            |event, _| received.push(event)
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        first.emit_owned(1);
+        second.emit_owned(3);
+        first.emit_owned(2);
+
+        first.close();
+        second.close();
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert_eq!(received, [1, 2, 3]);
+}
+
+#[test]
+fn on_variant_receives_matching_variant() {
+    #[derive(Debug, Clone)]
+    enum Ev {
+        A(i32),
+        B(String),
+    }
+
+    let source: EventSource!(Ev) = EventSource::new();
+    let mut received = Vec::new();
+    let mut others = Vec::new();
+
+    {
+        let mut future = pin!(on_variant!(source, Ev::A, |value, flow| {
+            received.push(value);
+            if value == 2 {
+                flow.set_done();
+            }
+        }));
+        let mut other = pin!(on_variant!(source, Ev::B, |value, _flow| others.push(value)));
+        assert!(poll_once(future.as_mut()).is_pending());
+        assert!(poll_once(other.as_mut()).is_pending());
+
+        source.emit_owned(Ev::A(1));
+        source.emit_owned(Ev::B(String::from("b")));
+        source.emit_owned(Ev::A(2));
+        source.emit_owned(Ev::A(3));
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert_eq!(received, [1, 2]);
+    assert_eq!(others, ["b"]);
+}
@@ -0,0 +1,82 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use core::{
+    future::Future,
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    task::Wake,
+};
+
+use common::poll_once;
+use event_source::BoundedEventSource;
+
+#[derive(Default)]
+struct FlagWaker(AtomicBool);
+
+impl Wake for FlagWaker {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn full_listener_blocks_emit_async() {
+    let source = BoundedEventSource::<i32>::new();
+    let mut received = Vec::new();
+
+    {
+        let mut listener = pin!(source.on_bounded(1, |event, _| received.push(event)));
+        assert!(poll_once(listener.as_mut()).is_pending());
+
+        assert_eq!(poll_once(pin!(source.emit_async(1))), Poll::Ready(1));
+
+        let woken = Arc::new(FlagWaker::default());
+        let waker = Waker::from(woken.clone());
+        let mut emit = pin!(source.emit_async(2));
+        assert!(emit
+            .as_mut()
+            .poll(&mut Context::from_waker(&waker))
+            .is_pending());
+
+        assert!(poll_once(listener.as_mut()).is_pending());
+        assert!(woken.0.load(Ordering::Relaxed));
+        assert_eq!(poll_once(emit.as_mut()), Poll::Ready(1));
+
+        assert!(poll_once(listener.as_mut()).is_pending());
+    }
+
+    assert_eq!(received, [1, 2]);
+}
+
+#[test]
+fn buffered_events_delivered_on_close() {
+    let source = BoundedEventSource::<i32>::new();
+    let mut received = Vec::new();
+
+    {
+        let mut listener = pin!(source.on_bounded(4, |event, _| received.push(event)));
+        assert!(poll_once(listener.as_mut()).is_pending());
+
+        source.emit_owned(1);
+        source.emit_owned(2);
+        source.close();
+
+        assert!(poll_once(listener.as_mut()).is_ready());
+    }
+
+    assert_eq!(received, [1, 2]);
+}
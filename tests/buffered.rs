@@ -0,0 +1,339 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use core::{pin::pin, task::Poll};
+
+use common::poll_once;
+use event_source::{DropPolicy, EventSlot, EventSource};
+
+#[test]
+fn window_grows_then_slides() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut windows = Vec::new();
+
+    {
+        let mut future = pin!(source.on_windowed(3, |window, _| windows.push(window.to_vec())));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for i in 1..=4 {
+            source.emit_owned(i);
+        }
+    }
+
+    assert_eq!(windows, [vec![1], vec![1, 2], vec![1, 2, 3], vec![2, 3, 4]]);
+}
+
+#[test]
+#[should_panic(expected = "window must be greater than zero")]
+fn empty_window_rejected() {
+    let source: EventSource!(i32) = EventSource::new();
+    let _ = poll_once(pin!(source.on_windowed(0, |_, _| {})));
+}
+
+#[test]
+fn chunks_flushed_on_close() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut chunks = Vec::new();
+
+    {
+        let mut future = pin!(source.on_chunked(3, true, |chunk, _| chunks.push(chunk)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for i in 1..=7 {
+            source.emit_owned(i);
+        }
+        source.close();
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert_eq!(chunks, [vec![1, 2, 3], vec![4, 5, 6], vec![7]]);
+}
+
+#[test]
+#[should_panic(expected = "size must be greater than zero")]
+fn empty_chunk_rejected() {
+    let source: EventSource!(i32) = EventSource::new();
+    let _ = poll_once(pin!(source.on_chunked(0, true, |_, _| {})));
+}
+
+#[test]
+fn groups_flushed_in_key_order() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut groups = Vec::new();
+
+    {
+        let mut future = pin!(source.on_grouped(
+            |event, _| event % 2 == 0,
+            |&even, events| groups.push((even, events.to_vec()))
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for i in 1..=5 {
+            source.emit_owned(i);
+        }
+        source.close();
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert_eq!(groups, [(false, vec![1, 3, 5]), (true, vec![2, 4])]);
+}
+
+#[test]
+fn groups_flushed_once_finished() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut groups = Vec::new();
+
+    {
+        let mut future = pin!(source.on_grouped(
+            |&event, flow| {
+                if event == 4 {
+                    flow.set_done();
+                }
+                event % 2 == 0
+            },
+            |&even, events| groups.push((even, events.to_vec()))
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for i in 1..=5 {
+            source.emit_owned(i);
+        }
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert!(!source.is_closed());
+    assert_eq!(groups, [(false, vec![1, 3]), (true, vec![2, 4])]);
+}
+
+#[test]
+fn lossy_drops_oldest_when_full() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut received = Vec::new();
+
+    let dropped = {
+        let mut future =
+            pin!(source.on_lossy(2, DropPolicy::Oldest, |event, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in 1..=5 {
+            source.emit_owned(event);
+        }
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        source.close();
+        match poll_once(future.as_mut()) {
+            Poll::Ready(dropped) => dropped,
+            Poll::Pending => panic!("listener is not finished after close"),
+        }
+    };
+
+    assert_eq!(dropped, 3);
+    assert_eq!(received, [4, 5]);
+}
+
+#[test]
+fn latest_delivers_most_recent_event() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_latest(|event, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in 1..=3 {
+            source.emit_owned(event);
+        }
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    assert_eq!(received, [3]);
+}
+
+#[test]
+fn latest_delivered_on_close() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_latest(|event, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        source.emit_owned(1);
+        source.emit_owned(2);
+        source.close();
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert_eq!(received, [2]);
+}
+
+#[test]
+fn snapshot_sums_cloned_values() {
+    let source: EventSource!(&mut i32) = EventSource::new();
+    let mut sum = 0;
+
+    {
+        let mut future = pin!(source.on_snapshot(|value, _| sum += value));
+        let mut doubling = pin!(source.on(|value: &mut i32, _| *value *= 2));
+        assert!(poll_once(future.as_mut()).is_pending());
+        assert!(poll_once(doubling.as_mut()).is_pending());
+
+        for mut value in [1, 2] {
+            source.with_emitter(|mut emitter| while emitter.emit_next(&mut value).is_some() {});
+            assert_eq!(value % 2, 0);
+        }
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    assert_eq!(sum, 3);
+}
+
+#[test]
+fn snapshots_delivered_on_close() {
+    let source: EventSource!(&mut i32) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_snapshot(|value, _| received.push(value)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for mut value in [1, 2] {
+            source.with_emitter(|mut emitter| while emitter.emit_next(&mut value).is_some() {});
+        }
+        source.close();
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    assert_eq!(received, [1, 2]);
+}
+
+#[test]
+fn initial_value_delivered_before_events() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_with_initial(0, |event, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        source.emit_owned(1);
+    }
+
+    assert_eq!(received, [0, 1]);
+}
+
+#[test]
+fn dropped_events_reported() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut dropped = Vec::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_lossy_with_dropped(
+            2,
+            DropPolicy::Newest,
+            |&event| dropped.push(event),
+            |event, _| received.push(event)
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in 1..=4 {
+            source.emit_owned(event);
+        }
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    assert_eq!(dropped, [3, 4]);
+    assert_eq!(received, [1, 2]);
+}
+
+#[test]
+fn lossy_buffer_delivered_on_close() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut dropped = Vec::new();
+    let mut received = Vec::new();
+
+    let count = {
+        let mut future = pin!(source.on_lossy_with_dropped(
+            2,
+            DropPolicy::Oldest,
+            |&event| dropped.push(event),
+            |event, _| received.push(event)
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in 1..=3 {
+            source.emit_owned(event);
+        }
+        source.close();
+
+        match poll_once(future.as_mut()) {
+            Poll::Ready(count) => count,
+            Poll::Pending => panic!("listener is not finished after close"),
+        }
+    };
+
+    assert_eq!(count, 1);
+    assert_eq!(dropped, [1]);
+    assert_eq!(received, [2, 3]);
+}
+
+#[test]
+fn slot_holds_latest_event() {
+    let source: EventSource!(i32) = EventSource::new();
+    let slot = EventSlot::new();
+
+    let mut future = pin!(source.on_into_slot(&slot));
+    assert!(poll_once(future.as_mut()).is_pending());
+    assert_eq!(slot.get(), None);
+
+    source.emit_owned(1);
+    assert_eq!(slot.get(), Some(1));
+
+    source.emit_owned(2);
+    source.emit_owned(3);
+    assert_eq!(slot.take(), Some(3));
+    assert_eq!(slot.get(), None);
+}
+
+#[test]
+fn reordered_holds_back_until_gap_filled() {
+    let source: EventSource!(u64) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on_reordered(4, |&seq| seq, |seq, _| received.push(seq)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        source.emit_owned(1);
+        source.emit_owned(3);
+        source.emit_owned(2);
+    }
+
+    assert_eq!(received, [1, 2, 3]);
+}
+
+#[test]
+fn diffed_delivers_deltas_between_events() {
+    let source: EventSource!(i32) = EventSource::new();
+    let mut deltas = Vec::new();
+
+    {
+        let mut future =
+            pin!(source.on_diffed(|prev, next| next - prev, |delta, _| deltas.push(delta)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in [10, 13, 11] {
+            source.emit_owned(event);
+        }
+    }
+
+    assert_eq!(deltas, [3, -2]);
+}
@@ -0,0 +1,135 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+mod common;
+
+use core::{
+    pin::pin,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use common::poll_once;
+use event_source::{emit, EventSource};
+
+#[test]
+fn drop_before_poll() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = AtomicUsize::new(0);
+
+    drop(source.on(|_, _| {
+        called.fetch_add(1, Ordering::Relaxed);
+    }));
+
+    assert_eq!(source.listener_count(), 0);
+    emit!(source, &1);
+    assert_eq!(called.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn drop_after_registration() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = AtomicUsize::new(0);
+
+    {
+        let mut future = pin!(source.on(|_, _| {
+            called.fetch_add(1, Ordering::Relaxed);
+        }));
+        assert!(poll_once(future.as_mut()).is_pending());
+        assert_eq!(source.listener_count(), 1);
+
+        emit!(source, &1);
+    }
+
+    assert_eq!(source.listener_count(), 0);
+    emit!(source, &2);
+    assert_eq!(called.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn drop_after_completion() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = AtomicUsize::new(0);
+
+    {
+        let mut future = pin!(source.on(|_, flow| {
+            called.fetch_add(1, Ordering::Relaxed);
+            flow.set_done();
+        }));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1);
+        assert!(poll_once(future.as_mut()).is_ready());
+        assert_eq!(source.listener_count(), 1);
+    }
+
+    assert_eq!(source.listener_count(), 0);
+    emit!(source, &2);
+    assert_eq!(called.load(Ordering::Relaxed), 1);
+}
+
+#[tokio::test]
+async fn select_cancels_on() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = AtomicUsize::new(0);
+
+    for _ in 0..3 {
+        tokio::select! {
+            biased;
+
+            _ = source.on(|_, _| {
+                called.fetch_add(1, Ordering::Relaxed);
+            }) => unreachable!(),
+            _ = tokio::task::yield_now() => {}
+        }
+
+        assert_eq!(source.listener_count(), 0);
+    }
+
+    emit!(source, &1);
+    assert_eq!(called.load(Ordering::Relaxed), 0);
+}
+
+#[tokio::test]
+async fn select_cancels_once() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let output = tokio::select! {
+        biased;
+
+        output = source.once(|value, _| Some(*value)) => output,
+        _ = tokio::task::yield_now() => None,
+    };
+
+    assert_eq!(output, None);
+    assert_eq!(source.listener_count(), 0);
+    emit!(source, &1);
+}
+
+#[tokio::test]
+async fn select_cancels_losing_listener() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = AtomicUsize::new(0);
+
+    let emit_task = async {
+        tokio::task::yield_now().await;
+        emit!(source, &1);
+        core::future::pending::<()>().await;
+    };
+
+    let output = tokio::select! {
+        output = source.once(|value, _| Some(*value)) => output,
+        _ = source.on(|_, _| {
+            called.fetch_add(1, Ordering::Relaxed);
+        }) => unreachable!(),
+        _ = emit_task => unreachable!(),
+    };
+
+    assert_eq!(output, Some(1));
+    assert_eq!(source.listener_count(), 0);
+
+    emit!(source, &2);
+    assert_eq!(called.load(Ordering::Relaxed), 1);
+}
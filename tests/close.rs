@@ -0,0 +1,34 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+use std::thread;
+
+use event_source::{ClosedError, EventSource};
+use futures::executor::block_on;
+
+#[test]
+fn listener_registered_while_closing_resolves() {
+    for _ in 0..100 {
+        let source: EventSource!(&i32) = EventSource::new();
+
+        thread::scope(|scope| {
+            let listener = scope.spawn(|| block_on(source.on(|_, _| {})));
+            scope.spawn(|| source.close());
+
+            listener.join().unwrap();
+        });
+        assert!(source.is_closed());
+    }
+}
+
+#[test]
+fn try_on_rejects_closed_source() {
+    let source: EventSource!(&i32) = EventSource::new();
+    assert!(source.try_on(|_, _| {}).is_ok());
+
+    source.close();
+    assert_eq!(source.try_on(|_, _| {}).err(), Some(ClosedError));
+}
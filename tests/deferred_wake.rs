@@ -0,0 +1,210 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+mod common;
+
+use core::{
+    future::Future,
+    pin::pin,
+    task::{Context, Waker},
+    time::Duration,
+};
+use std::{
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    task::Wake,
+};
+
+use common::WakeCounter;
+use event_source::{emit, EventSource};
+
+type Source = EventSource!(&i32);
+
+struct LockProbe {
+    source: Arc<Source>,
+    woken: AtomicUsize,
+    locked_on_wake: AtomicBool,
+}
+
+impl Wake for LockProbe {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.fetch_add(1, Ordering::Relaxed);
+
+        if self
+            .source
+            .try_with_emitter_for(Duration::ZERO, |_| ())
+            .is_none()
+        {
+            self.locked_on_wake.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+#[test]
+fn finished_listener_woken_after_lock_released() {
+    let source = Arc::new(Source::new());
+    let probe = Arc::new(LockProbe {
+        source: source.clone(),
+        woken: AtomicUsize::new(0),
+        locked_on_wake: AtomicBool::new(false),
+    });
+    let waker = Waker::from(probe.clone());
+
+    let mut future = pin!(source.on(|_, flow| flow.set_done()));
+    assert!(future
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker))
+        .is_pending());
+
+    emit!(source, &1);
+
+    assert_eq!(probe.woken.load(Ordering::Relaxed), 1);
+    assert!(!probe.locked_on_wake.load(Ordering::Relaxed));
+}
+
+fn probe(source: &Arc<Source>) -> Arc<LockProbe> {
+    Arc::new(LockProbe {
+        source: source.clone(),
+        woken: AtomicUsize::new(0),
+        locked_on_wake: AtomicBool::new(false),
+    })
+}
+
+#[test]
+fn many_finished_listeners_woken_after_lock_released() {
+    let source = Arc::new(Source::new());
+    let probes: [_; 20] = core::array::from_fn(|_| probe(&source));
+
+    let mut futures: [_; 20] =
+        core::array::from_fn(|_| Box::pin(source.on(|_, flow| flow.set_done())));
+    for (future, probe) in futures.iter_mut().zip(&probes) {
+        let waker = Waker::from(probe.clone());
+        assert!(future
+            .as_mut()
+            .poll(&mut Context::from_waker(&waker))
+            .is_pending());
+    }
+
+    emit!(source, &1);
+
+    for probe in &probes {
+        assert_eq!(probe.woken.load(Ordering::Relaxed), 1);
+        assert!(!probe.locked_on_wake.load(Ordering::Relaxed));
+    }
+}
+
+#[test]
+fn listener_finished_by_for_each_woken_after_lock_released() {
+    let source = Arc::new(Source::new());
+    let probe = probe(&source);
+    let waker = Waker::from(probe.clone());
+
+    let mut future = pin!(source.on(|_, _| {}));
+    assert!(future
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker))
+        .is_pending());
+
+    source.for_each_listener(|item| item.set_done());
+
+    assert_eq!(probe.woken.load(Ordering::Relaxed), 1);
+    assert!(!probe.locked_on_wake.load(Ordering::Relaxed));
+    assert!(future
+        .as_mut()
+        .poll(&mut Context::from_waker(&waker))
+        .is_ready());
+}
+
+#[test]
+fn repeated_wakes_coalesced_until_polled() {
+    let source: EventSource!(i32) = EventSource::new();
+    let counter = Arc::new(WakeCounter(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut future = pin!(source.on_latest(|_, _| {}));
+    assert!(future.as_mut().poll(&mut cx).is_pending());
+
+    for i in 0..100 {
+        source.emit_owned(i);
+    }
+    assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+
+    assert!(future.as_mut().poll(&mut cx).is_pending());
+    for i in 0..100 {
+        source.emit_owned(i);
+    }
+    assert_eq!(counter.0.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn batched_wakes_not_coalesced() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let counter = Arc::new(WakeCounter(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut future = pin!(source.on_with_batched_wakes(5, |&event, flow| {
+        if event == 10 {
+            flow.set_done();
+        }
+    }));
+    assert!(future.as_mut().poll(&mut cx).is_pending());
+
+    for i in 0..10 {
+        emit!(source, &i);
+    }
+    assert_eq!(counter.0.load(Ordering::Relaxed), 2);
+
+    emit!(source, &10);
+    assert_eq!(counter.0.load(Ordering::Relaxed), 3);
+    assert!(future.as_mut().poll(&mut cx).is_ready());
+}
+
+#[test]
+fn listeners_of_one_task_woken_once() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let counter = Arc::new(WakeCounter(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+
+    let mut futures: [_; 10] =
+        core::array::from_fn(|_| Box::pin(source.on(|_, flow| flow.set_done())));
+    for future in &mut futures {
+        assert!(future.as_mut().poll(&mut cx).is_pending());
+    }
+
+    emit!(source, &1);
+    assert_eq!(counter.0.load(Ordering::Relaxed), 1);
+    for future in &mut futures {
+        assert!(future.as_mut().poll(&mut cx).is_ready());
+    }
+}
+
+#[test]
+fn interleaved_listeners_not_merged() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let counter = Arc::new(WakeCounter(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let mut cx = Context::from_waker(&waker);
+    let mut other_cx = Context::from_waker(Waker::noop());
+
+    let mut first = Box::pin(source.on(|_, flow| flow.set_done()));
+    let mut other = Box::pin(source.on(|_, flow| flow.set_done()));
+    let mut last = Box::pin(source.on(|_, flow| flow.set_done()));
+    assert!(first.as_mut().poll(&mut cx).is_pending());
+    assert!(other.as_mut().poll(&mut other_cx).is_pending());
+    assert!(last.as_mut().poll(&mut cx).is_pending());
+
+    emit!(source, &1);
+    assert_eq!(counter.0.load(Ordering::Relaxed), 2);
+}
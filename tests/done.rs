@@ -0,0 +1,84 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use core::pin::pin;
+use std::{sync::mpsc, thread};
+
+use common::poll_once;
+use event_source::{emit, EventSource};
+use futures::executor::block_on;
+
+#[test]
+fn done_signal_woken_on_close() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let (future, done) = source.on_with_done_signal(|_, _| {});
+    let mut future = pin!(future);
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    let (finished, on_finished) = mpsc::channel();
+    thread::scope(|scope| {
+        scope.spawn(move || {
+            block_on(done);
+            finished.send(()).unwrap();
+        });
+
+        emit!(source, &1);
+        assert!(on_finished.try_recv().is_err());
+
+        source.close();
+        on_finished.recv().unwrap();
+    });
+    assert!(poll_once(future.as_mut()).is_ready());
+}
+
+#[test]
+fn done_signal_on_finish() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let (future, done) = source.on_with_done_signal(|_, flow| flow.set_done());
+    let mut future = pin!(future);
+    let mut done = pin!(done);
+    assert!(poll_once(future.as_mut()).is_pending());
+    assert!(poll_once(done.as_mut()).is_pending());
+
+    emit!(source, &1);
+    assert!(poll_once(done.as_mut()).is_ready());
+    assert!(poll_once(future.as_mut()).is_ready());
+}
+
+#[test]
+fn done_signal_on_finish_from_outside() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let (future, done) = source.on_with_done_signal(|_, _| {});
+    let mut future = pin!(future);
+    let mut done = pin!(done);
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    source.for_each_listener(|item| item.set_done());
+    assert!(poll_once(done.as_mut()).is_ready());
+    assert!(poll_once(future.as_mut()).is_ready());
+}
+
+#[test]
+fn done_signal_on_drop() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let (future, done) = source.on_with_done_signal(|_, _| {});
+    let mut done = pin!(done);
+    {
+        let mut future = pin!(future);
+        assert!(poll_once(future.as_mut()).is_pending());
+        assert!(poll_once(done.as_mut()).is_pending());
+    }
+
+    assert!(poll_once(done.as_mut()).is_ready());
+}
@@ -0,0 +1,413 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+mod common;
+
+use core::{
+    pin::pin,
+    task::{Context, Poll, Waker},
+};
+
+use common::poll_once;
+use event_source::{EmitReport, EventSource, ResumeToken};
+
+#[test]
+fn priority_delivered_before_resumed_emission() {
+    let source: EventSource!(&'static str) = EventSource::new();
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+
+    {
+        let mut future_first = pin!(source.on(|event, _| first.push(event)));
+        let mut future_second = pin!(source.on(|event, _| second.push(event)));
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+
+        let mut token: Option<ResumeToken> = None;
+        source.with_emitter(|mut emitter| {
+            emitter.emit_next("normal");
+            token = Some(emitter.token());
+        });
+
+        assert_eq!(source.emit_owned("priority"), 2);
+
+        assert!(source.with_emitter_from(token.unwrap(), |mut emitter| {
+            while emitter.emit_next("normal").is_some() {}
+        }));
+    }
+
+    assert_eq!(first, ["normal", "priority"]);
+    assert_eq!(second, ["priority", "normal"]);
+}
+
+#[test]
+fn emit_to_id_reaches_one_listener() {
+    let source: EventSource!(&'static str) = EventSource::new();
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+
+    {
+        let (first_id, future_first) = source.on_with_id(|event, _| first.push(event));
+        let (second_id, future_second) = source.on_with_id(|event, _| second.push(event));
+        assert_ne!(first_id, second_id);
+
+        let mut future_first = pin!(future_first);
+        let mut future_second = pin!(future_second);
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+
+        assert!(source.emit_to_id(second_id, "second"));
+        assert!(source.emit_to_id(first_id, "first"));
+    }
+
+    assert_eq!(first, ["first"]);
+    assert_eq!(second, ["second"]);
+}
+
+#[test]
+fn emit_if_skips_event_without_listeners() {
+    let source: EventSource!(&mut i32) = EventSource::new();
+
+    let mut made = false;
+    source.emit_if(|count| count > 0, |_| made = true);
+    assert!(!made);
+
+    let mut received = Vec::new();
+    {
+        let mut future = pin!(source.on(|value: &mut i32, _| {
+            received.push(*value);
+            *value += 1;
+        }));
+        let mut second = pin!(source.on(|value: &mut i32, _| *value *= 10));
+        assert!(poll_once(future.as_mut()).is_pending());
+        assert!(poll_once(second.as_mut()).is_pending());
+
+        let mut value = 0;
+        source.emit_if(
+            |count| count == 2,
+            |mut emitter| {
+                value = 1;
+                while emitter.emit_next(&mut value).is_some() {}
+            },
+        );
+        assert_eq!(value, 20);
+
+        source.emit_if(|_| false, |_| unreachable!());
+    }
+
+    assert_eq!(received, [1]);
+}
+
+#[test]
+fn emit_owned_clones_for_each_listener() {
+    let source: EventSource!(String) = EventSource::new();
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+
+    assert_eq!(source.emit_owned(String::from("dropped")), 0);
+
+    {
+        let mut future_first = pin!(source.on(|event, _| first.push(event)));
+        let mut future_second = pin!(source.on(|event, _| second.push(event)));
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+
+        assert_eq!(source.emit_owned(String::from("event")), 2);
+    }
+
+    assert_eq!(first, ["event"]);
+    assert_eq!(second, ["event"]);
+}
+
+#[test]
+fn emitter_reports_position_and_remaining() {
+    let source: EventSource!(&'static str) = EventSource::new();
+
+    let mut futures = [(); 4].map(|_| Box::pin(source.on(|_, _| {})));
+    for future in &mut futures {
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    let mut cursor = None;
+    source.with_emitter(|mut emitter| {
+        emitter.emit_next("first");
+        emitter.emit_next("second");
+        cursor = Some((emitter.position(), emitter.remaining()));
+    });
+
+    assert_eq!(cursor, Some((2, 2)));
+}
+
+#[test]
+fn emit_to_index_reaches_one_listener() {
+    let source: EventSource!(&'static str) = EventSource::new();
+    let mut received = [const { Vec::new() }; 3];
+
+    {
+        let [first, second, third] = &mut received;
+        let mut future_first = pin!(source.on(|event, _| first.push(event)));
+        let mut future_second = pin!(source.on(|event, _| second.push(event)));
+        let mut future_third = pin!(source.on(|event, _| third.push(event)));
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+        assert!(poll_once(future_third.as_mut()).is_pending());
+
+        assert!(source.emit_to_index(1, "event"));
+        assert!(!source.emit_to_index(3, "missing"));
+    }
+
+    assert_eq!(received, [vec![], vec!["event"], vec![]]);
+}
+
+#[test]
+fn emit_batch_delivers_every_event_in_order() {
+    let source: EventSource!(&'static str) = EventSource::new();
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+
+    {
+        let mut future_first = pin!(source.on(|event, _| first.push(event)));
+        let mut future_second = pin!(source.on(|event, _| second.push(event)));
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+
+        source.emit_batch(["a", "b", "c"]);
+    }
+
+    assert_eq!(first, ["a", "b", "c"]);
+    assert_eq!(second, ["a", "b", "c"]);
+}
+
+#[test]
+fn resumed_emission_delivers_rest() {
+    let source: EventSource!(&'static str) = EventSource::new();
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+
+    {
+        let mut future_first = pin!(source.on(|event, _| first.push(event)));
+        let mut future_second = pin!(source.on(|event, _| second.push(event)));
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+
+        let mut token: Option<ResumeToken> = None;
+        source.with_emitter(|mut emitter| {
+            emitter.emit_next("event");
+            token = Some(emitter.token());
+        });
+        let token = token.unwrap();
+
+        assert!(source.with_emitter_from(token, |mut emitter| {
+            while emitter.emit_next("event").is_some() {}
+        }));
+
+        let mut future_third = pin!(source.on(|_, _| {}));
+        assert!(poll_once(future_third.as_mut()).is_pending());
+        assert!(!source.with_emitter_from(token, |_| unreachable!()));
+    }
+
+    assert_eq!(first, ["event"]);
+    assert_eq!(second, ["event"]);
+}
+
+#[test]
+fn emit_and_report_counts_completed_listeners() {
+    let source: EventSource!(&'static str) = EventSource::new();
+
+    let mut finishing = pin!(source.on(|_, flow| flow.set_done()));
+    let mut listening = pin!(source.on(|_, _| {}));
+    assert!(poll_once(finishing.as_mut()).is_pending());
+    assert!(poll_once(listening.as_mut()).is_pending());
+
+    assert_eq!(
+        source.emit_and_report("event"),
+        EmitReport {
+            notified: 2,
+            completed: 1,
+        }
+    );
+}
+
+#[test]
+fn try_with_emitter_for_times_out_while_locked() {
+    use std::{sync::mpsc, thread, time::Duration};
+
+    let source: EventSource!(&'static str) = EventSource::new();
+    let (locked, wait_locked) = mpsc::channel();
+    let (release, wait_release) = mpsc::channel::<()>();
+
+    let source = &source;
+    thread::scope(|scope| {
+        scope.spawn(move || {
+            source.with_emitter(|_| {
+                locked.send(()).unwrap();
+                let _ = wait_release.recv();
+            });
+        });
+
+        wait_locked.recv().unwrap();
+        assert_eq!(
+            source.try_with_emitter_for(Duration::from_millis(10), |_| ()),
+            None
+        );
+        release.send(()).unwrap();
+    });
+
+    assert_eq!(
+        source.try_with_emitter_for(Duration::from_millis(10), |emitter| emitter.remaining()),
+        Some(0)
+    );
+}
+
+#[test]
+fn skipped_emission_visible_as_generation_gap() {
+    let source: EventSource!(&'static str) = EventSource::new();
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+
+    {
+        let mut future_first = pin!(source.on(|_, flow| first.push(flow.generation())));
+        let mut future_second = pin!(source.on(|_, flow| second.push(flow.generation())));
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+
+        source.emit_owned("both");
+        assert!(source.emit_to_index(0, "first only"));
+        source.emit_owned("both");
+    }
+
+    assert_eq!(first, [first[0], first[0] + 1, first[0] + 2]);
+    assert_eq!(second, [first[0], first[0] + 2]);
+}
+
+#[test]
+fn emit_until_ack_stops_at_acking_listener() {
+    let source: EventSource!(&'static str) = EventSource::new();
+    let mut received = [const { Vec::new() }; 3];
+
+    {
+        let [first, second, third] = &mut received;
+        let mut future_first = pin!(source.on(|event, _| first.push(event)));
+        let mut future_second = pin!(source.on(|event, flow| {
+            second.push(event);
+            flow.ack();
+        }));
+        let mut future_third = pin!(source.on(|event, _| third.push(event)));
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+        assert!(poll_once(future_third.as_mut()).is_pending());
+
+        assert!(source.emit_until_ack("event"));
+    }
+
+    assert_eq!(received, [vec!["event"], vec!["event"], vec![]]);
+}
+
+#[test]
+fn poll_emit_completes_once_lock_released() {
+    use std::{sync::mpsc, thread};
+
+    let source: EventSource!(&'static str) = EventSource::new();
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on(|event, _| received.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        let (locked, wait_locked) = mpsc::channel();
+        let (release, wait_release) = mpsc::channel::<()>();
+        let source = &source;
+        thread::scope(|scope| {
+            scope.spawn(move || {
+                source.with_emitter(|_| {
+                    locked.send(()).unwrap();
+                    let _ = wait_release.recv();
+                });
+            });
+
+            wait_locked.recv().unwrap();
+            let mut cx = Context::from_waker(Waker::noop());
+            assert_eq!(source.poll_emit(&mut cx, "event"), Poll::Pending);
+            release.send(()).unwrap();
+
+            while source.poll_emit(&mut cx, "event").is_pending() {}
+        });
+    }
+
+    assert_eq!(received, ["event"]);
+}
+
+#[test]
+fn shared_value_computed_once_for_every_listener() {
+    let source: EventSource!(&String) = EventSource::new();
+    let mut received = [const { Vec::new() }; 3];
+    let mut computed = 0;
+    let mut shared = 0;
+
+    {
+        let [first, second, third] = &mut received;
+        let mut future_first =
+            pin!(source.on(|event: &String, _| first.push(event as *const _ as usize)));
+        let mut future_second =
+            pin!(source.on(|event: &String, _| second.push(event as *const _ as usize)));
+        let mut future_third =
+            pin!(source.on(|event: &String, _| third.push(event as *const _ as usize)));
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+        assert!(poll_once(future_third.as_mut()).is_pending());
+
+        source.with_emitter_shared(
+            || {
+                computed += 1;
+                String::from("shared")
+            },
+            |mut emitter| {
+                shared = emitter.value() as *const _ as usize;
+                emitter.emit_all();
+            },
+        );
+    }
+
+    assert_eq!(computed, 1);
+    assert_eq!(received, [[shared], [shared], [shared]]);
+}
+
+#[test]
+fn emit_chain_returns_consuming_handler() {
+    let source: EventSource!(&'static str) = EventSource::new();
+    let mut received = [const { Vec::new() }; 3];
+
+    {
+        let [first, second, third] = &mut received;
+        let (_, future_first) = source.on_with_id(|event, _| first.push(event));
+        let (second_id, future_second) = source.on_with_id(|event, flow| {
+            second.push(event);
+            if event == "consumed" {
+                flow.ack();
+            }
+        });
+        let (_, future_third) = source.on_with_id(|event, _| third.push(event));
+
+        let mut future_first = pin!(future_first);
+        let mut future_second = pin!(future_second);
+        let mut future_third = pin!(future_third);
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+        assert!(poll_once(future_third.as_mut()).is_pending());
+
+        assert_eq!(source.emit_chain("consumed"), Some(second_id));
+        assert_eq!(source.emit_chain("passed"), None);
+    }
+
+    assert_eq!(
+        received,
+        [
+            vec!["consumed", "passed"],
+            vec!["consumed", "passed"],
+            vec!["passed"]
+        ]
+    );
+}
@@ -0,0 +1,73 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+mod common;
+
+use core::pin::pin;
+use std::sync::Mutex;
+
+use common::poll_once;
+use event_source::{CapacityError, StaticEventSource};
+
+#[test]
+fn remaining_listeners_skip_vacant_slots() {
+    let source: StaticEventSource!(4; &i32) = StaticEventSource::new();
+    let remaining = Mutex::new(Vec::new());
+
+    {
+        let mut first = pin!(source
+            .on(|_, flow| remaining.lock().unwrap().push(flow.remaining_listeners()))
+            .unwrap());
+        let vacant = source.on(|_, _| {}).unwrap();
+        let mut third = pin!(source
+            .on(|_, flow| remaining.lock().unwrap().push(flow.remaining_listeners()))
+            .unwrap());
+        drop(vacant);
+
+        assert!(poll_once(first.as_mut()).is_pending());
+        assert!(poll_once(third.as_mut()).is_pending());
+
+        source.with_emitter(|mut emitter| while emitter.emit_next(&1).is_some() {});
+    }
+
+    assert_eq!(remaining.into_inner().unwrap(), [1, 0]);
+}
+
+#[test]
+fn full_source_rejects_listener() {
+    let source: StaticEventSource!(1; &i32) = StaticEventSource::new();
+
+    let _first = source.on(|_, _| {}).unwrap();
+    assert_eq!(source.on(|_, _| {}).err(), Some(CapacityError));
+}
+
+#[test]
+fn every_slot_notified_once_full() {
+    let source: StaticEventSource!(3; &i32) = StaticEventSource::new();
+    let received = Mutex::new(Vec::new());
+
+    {
+        let mut first = pin!(source
+            .on(|&event, _| received.lock().unwrap().push(event))
+            .unwrap());
+        let mut second = pin!(source
+            .on(|&event, _| received.lock().unwrap().push(event * 10))
+            .unwrap());
+        let mut third = pin!(source
+            .on(|&event, _| received.lock().unwrap().push(event * 100))
+            .unwrap());
+        assert_eq!(source.on(|_, _| {}).err(), Some(CapacityError));
+
+        assert!(poll_once(first.as_mut()).is_pending());
+        assert!(poll_once(second.as_mut()).is_pending());
+        assert!(poll_once(third.as_mut()).is_pending());
+
+        source.with_emitter(|mut emitter| while emitter.emit_next(&1).is_some() {});
+    }
+
+    assert_eq!(received.into_inner().unwrap(), [1, 10, 100]);
+    assert!(source.on(|_, _| {}).is_ok());
+}
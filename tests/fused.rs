@@ -0,0 +1,59 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "futures")]
+
+mod common;
+
+use core::pin::pin;
+
+use common::poll_once;
+use event_source::{emit, EventSource};
+use futures::{executor::block_on, future::FusedFuture, select};
+
+#[test]
+fn terminated_inside_select() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let mut future = pin!(source.on(|_, flow| flow.set_done()));
+    assert!(poll_once(future.as_mut()).is_pending());
+    emit!(source, &1);
+    assert!(!future.is_terminated());
+
+    let completed = block_on(async {
+        let mut completed = 0;
+        loop {
+            select! {
+                _ = future => completed += 1,
+                complete => break completed,
+            }
+        }
+    });
+    assert_eq!(completed, 1);
+    assert!(future.is_terminated());
+}
+
+#[test]
+fn terminated_after_close() {
+    let source: EventSource!(&i32) = EventSource::new();
+    source.close();
+
+    let mut future = pin!(source.on(|_, _| {}));
+    assert!(!future.is_terminated());
+
+    let completed = block_on(async {
+        let mut completed = 0;
+        loop {
+            select! {
+                _ = future => completed += 1,
+                complete => break completed,
+            }
+        }
+    });
+    assert_eq!(completed, 1);
+    assert!(future.is_terminated());
+    assert!(poll_once(future.as_mut()).is_ready());
+}
@@ -0,0 +1,110 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use core::{
+    future::Future,
+    pin::pin,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::{sync::mpsc, thread};
+
+use common::poll_once;
+use event_source::{emit, EventSource};
+use futures::executor::block_on;
+
+#[test]
+fn cancel_from_another_task() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = AtomicUsize::new(0);
+
+    let (low, _) = source.on_with_priority(1, |_, _| {});
+    let (high, handle) = source.on_with_priority(0, |_, _| {
+        called.fetch_add(1, Ordering::Relaxed);
+    });
+    let mut low = pin!(low);
+    assert!(poll_once(low.as_mut()).is_pending());
+
+    let (registered, on_registered) = mpsc::channel();
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            let mut high = pin!(high);
+            block_on(core::future::poll_fn(|cx| {
+                let poll = high.as_mut().poll(cx);
+                let _ = registered.send(());
+                poll
+            }));
+        });
+
+        on_registered.recv().unwrap();
+        assert_eq!(source.listener_count(), 2);
+        emit!(source, &1);
+
+        let canceller = handle.clone();
+        scope
+            .spawn(move || assert!(canceller.cancel()))
+            .join()
+            .unwrap();
+        assert!(!handle.cancel());
+    });
+
+    assert_eq!(source.listener_count(), 1);
+    emit!(source, &2);
+    assert_eq!(called.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn cancel_before_first_poll() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = AtomicUsize::new(0);
+
+    let (future, handle) = source.on_with_priority(0, |_, _| {
+        called.fetch_add(1, Ordering::Relaxed);
+    });
+    assert!(handle.cancel());
+
+    let mut future = pin!(future);
+    assert!(poll_once(future.as_mut()).is_ready());
+    assert_eq!(source.listener_count(), 0);
+
+    emit!(source, &1);
+    assert_eq!(called.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn cancel_finished_listener() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let (future, handle) = source.on_with_priority(0, |_, flow| flow.set_done());
+    let mut future = pin!(future);
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    emit!(source, &1);
+    assert!(!handle.cancel());
+    assert_eq!(source.listener_count(), 0);
+    assert!(poll_once(future.as_mut()).is_ready());
+}
+
+#[test]
+fn cancel_dropped_listener() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let (future, handle) = source.on_with_priority(0, |_, _| {});
+    {
+        let mut future = pin!(future);
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    assert_eq!(source.listener_count(), 0);
+    assert!(!handle.cancel());
+
+    let (future, handle) = source.on_with_priority(0, |_, _| {});
+    drop(future);
+    assert!(!handle.cancel());
+}
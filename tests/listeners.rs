@@ -0,0 +1,227 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+mod common;
+
+use core::{pin::pin, task::Poll};
+
+use common::poll_once;
+use event_source::{emit, EventSource, ListenerDebug};
+
+#[test]
+fn for_each_listener_finishes_every_listener() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let mut first = pin!(source.on(|_, _| {}));
+    let mut second = pin!(source.on(|_, _| {}));
+    assert!(poll_once(first.as_mut()).is_pending());
+    assert!(poll_once(second.as_mut()).is_pending());
+
+    let mut visited = 0;
+    source.for_each_listener(|item| {
+        visited += 1;
+        item.set_done();
+    });
+
+    assert_eq!(visited, 2);
+    assert!(poll_once(first.as_mut()).is_ready());
+    assert!(poll_once(second.as_mut()).is_ready());
+}
+
+#[test]
+fn peek_front_reflects_finished_listener() {
+    let source: EventSource!(&i32) = EventSource::new();
+    assert_eq!(source.peek_front(), None);
+
+    let mut first = pin!(source.on(|_, flow| flow.set_done()));
+    let mut second = pin!(source.on(|_, _| {}));
+    assert!(poll_once(first.as_mut()).is_pending());
+    assert!(poll_once(second.as_mut()).is_pending());
+    assert_eq!(
+        source.peek_front(),
+        Some(ListenerDebug {
+            done: false,
+            has_waker: true,
+        })
+    );
+
+    emit!(source, &1);
+    assert_eq!(source.peek_front().map(|front| front.done), Some(true));
+}
+
+#[test]
+#[should_panic(expected = "EventSource has 1 listener(s) registered")]
+fn assert_no_listeners_reports_count() {
+    let source: EventSource!(&i32) = EventSource::new();
+    {
+        let mut dropped = pin!(source.on(|_, _| {}));
+        assert!(poll_once(dropped.as_mut()).is_pending());
+    }
+    source.assert_no_listeners();
+
+    let mut kept = pin!(source.on(|_, _| {}));
+    assert!(poll_once(kept.as_mut()).is_pending());
+    source.assert_no_listeners();
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn listeners_changed_reports_count() {
+    use std::sync::{Arc, Mutex};
+
+    let source: EventSource!(&i32) = EventSource::new();
+    let counts = Arc::new(Mutex::new(Vec::new()));
+    source.on_listeners_changed({
+        let counts = counts.clone();
+        move |count| counts.lock().unwrap().push(count)
+    });
+
+    {
+        let mut future = pin!(source.on(|_, _| {}));
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    assert_eq!(*counts.lock().unwrap(), [1, 0]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn subscription_counted_without_waker() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let mut future = pin!(source.on(|_, _| {}));
+    assert!(poll_once(future.as_mut()).is_pending());
+    assert_eq!(source.count_without_waker(), 0);
+
+    let subscription = source.subscribe(|_, _| {});
+    assert_eq!(source.count_without_waker(), 1);
+
+    drop(subscription);
+    assert_eq!(source.count_without_waker(), 0);
+}
+
+#[test]
+fn count_done_counts_finished_listeners_kept_alive() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let mut first = pin!(source.once(|&event, _| Some(event)));
+    let mut second = pin!(source.once(|&event, _| Some(event)));
+    let mut active = pin!(source.on(|_, _| {}));
+    assert!(poll_once(first.as_mut()).is_pending());
+    assert!(poll_once(second.as_mut()).is_pending());
+    assert!(poll_once(active.as_mut()).is_pending());
+    assert_eq!(source.count_done(), 0);
+
+    emit!(source, &1);
+    assert_eq!(source.count_done(), 2);
+    assert_eq!(source.listener_count(), 3);
+
+    assert_eq!(poll_once(first.as_mut()), Poll::Ready(Some(1)));
+    assert_eq!(source.count_done(), 1);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn listener_ids_in_emission_order() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let (first_id, first) = source.on_with_id(|_, _| {});
+    let (second_id, second) = source.on_with_id(|_, _| {});
+    let (third_id, third) = source.on_with_id(|_, _| {});
+    let mut first = pin!(first);
+    let mut third = pin!(third);
+    assert!(poll_once(first.as_mut()).is_pending());
+    {
+        let mut second = pin!(second);
+        assert!(poll_once(second.as_mut()).is_pending());
+    }
+    assert!(poll_once(third.as_mut()).is_pending());
+
+    let ids = source.listener_ids();
+    assert!(!ids.contains(&second_id));
+    assert_eq!(ids, [first_id, third_id]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn sleeping_listener_skipped_until_condition() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let source: EventSource!(&i32) = EventSource::new();
+    let counter = Arc::new(AtomicUsize::new(0));
+    let mut received = Vec::new();
+
+    {
+        let mut future = pin!(source.on(|&event, flow| {
+            received.push(event);
+            if event == 1 {
+                let counter = counter.clone();
+                flow.sleep_until_condition(move || counter.load(Ordering::Relaxed) >= 3);
+            }
+        }));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        for event in 1..=5 {
+            emit!(source, &event);
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    assert_eq!(received, [1, 4, 5]);
+}
+
+#[test]
+fn remaining_listeners_after_drop_and_finish() {
+    use std::sync::Mutex;
+
+    let source: EventSource!(&i32) = EventSource::new();
+    let remaining = Mutex::new(Vec::new());
+
+    let mut first =
+        pin!(source.on(|_, flow| remaining.lock().unwrap().push(flow.remaining_listeners())));
+    let mut dropped = Box::pin(source.on(|_, _| {}));
+    let mut finishing = Box::pin(source.on(|_, flow| flow.set_done()));
+    let mut last =
+        pin!(source.on(|_, flow| remaining.lock().unwrap().push(flow.remaining_listeners())));
+
+    assert!(poll_once(first.as_mut()).is_pending());
+    assert!(poll_once(dropped.as_mut()).is_pending());
+    assert!(poll_once(finishing.as_mut()).is_pending());
+    assert!(poll_once(last.as_mut()).is_pending());
+    drop(dropped);
+
+    emit!(source, &1);
+    assert_eq!(remaining.lock().unwrap().split_off(0), [2, 0]);
+
+    // Finished listener stays registered until its future is dropped
+    emit!(source, &2);
+    assert_eq!(remaining.lock().unwrap().split_off(0), [2, 0]);
+
+    assert!(poll_once(finishing.as_mut()).is_ready());
+    drop(finishing);
+    emit!(source, &3);
+    assert_eq!(remaining.lock().unwrap().split_off(0), [1, 0]);
+}
+
+#[test]
+fn for_each_listener_panics_on_reentry() {
+    let source: EventSource!(&i32) = EventSource::new();
+
+    let mut future = pin!(source.on(|_, _| {}));
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        source.for_each_listener(|_| {
+            source.listener_count();
+        });
+    }));
+
+    assert!(res.is_err());
+    assert_eq!(source.listener_count(), 1);
+}
@@ -0,0 +1,130 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+mod common;
+
+use core::pin::pin;
+use std::thread;
+
+use common::poll_once;
+use event_source::{emit, EventSource};
+
+const EMITTERS: usize = 8;
+const EVENTS: usize = 2000;
+
+#[test]
+fn concurrent_emitters_keep_per_emitter_order() {
+    let source: EventSource!(&(usize, usize)) = EventSource::new();
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+
+    {
+        let mut future_first = pin!(source.on(|&event, _| first.push(event)));
+        let mut future_second = pin!(source.on(|&event, _| second.push(event)));
+        assert!(poll_once(future_first.as_mut()).is_pending());
+        assert!(poll_once(future_second.as_mut()).is_pending());
+
+        thread::scope(|scope| {
+            for emitter in 0..EMITTERS {
+                let source = &source;
+
+                scope.spawn(move || {
+                    for seq in 0..EVENTS {
+                        emit!(source, &(emitter, seq));
+                    }
+                });
+            }
+        });
+    }
+
+    for events in [&first, &second] {
+        assert_eq!(events.len(), EMITTERS * EVENTS);
+
+        let mut next = [0; EMITTERS];
+        for &(emitter, seq) in events.iter() {
+            assert_eq!(
+                seq, next[emitter],
+                "event of emitter {emitter} out of order"
+            );
+            next[emitter] += 1;
+        }
+    }
+
+    // Each emission pass is done under the lock, so every listener observes same total order
+    assert_eq!(first, second);
+}
+
+#[derive(Debug, Default)]
+struct State {
+    first: usize,
+    second: usize,
+    visited: Vec<usize>,
+}
+
+#[test]
+fn exclusive_emission_keeps_invariants() {
+    const LISTENERS: usize = 3;
+
+    let source: EventSource!(&mut State) = EventSource::new();
+
+    let mut first = pin!(source.on(|state, _| {
+        assert_eq!(state.first, state.second);
+        state.first += 1;
+        state.visited.push(0);
+    }));
+    let mut second = pin!(source.on(|state, _| {
+        assert_eq!(state.first, state.second + 1);
+        state.second += 1;
+        state.visited.push(1);
+    }));
+    let mut third = pin!(source.on(|state, _| {
+        assert_eq!(state.first, state.second);
+        state.visited.push(2);
+    }));
+    assert!(poll_once(first.as_mut()).is_pending());
+    assert!(poll_once(second.as_mut()).is_pending());
+    assert!(poll_once(third.as_mut()).is_pending());
+
+    thread::scope(|scope| {
+        for _ in 0..EMITTERS {
+            let source = &source;
+
+            scope.spawn(move || {
+                let mut state = State::default();
+                for _ in 0..EVENTS {
+                    source.emit_exclusive(&mut state, |state| state);
+                }
+
+                assert_eq!(state.first, EVENTS);
+                assert_eq!(state.second, EVENTS);
+                assert_eq!(state.visited.len(), EVENTS * LISTENERS);
+                for pass in state.visited.chunks(LISTENERS) {
+                    assert_eq!(pass, [0, 1, 2]);
+                }
+            });
+        }
+    });
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn keyed_listeners_called_in_key_order() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let visited = std::sync::Mutex::new(Vec::new());
+
+    {
+        let mut b = pin!(source.on_keyed("b", |_, _| visited.lock().unwrap().push("b")));
+        let mut a = pin!(source.on_keyed("a", |_, _| visited.lock().unwrap().push("a")));
+        let mut c = pin!(source.on_keyed("c", |_, _| visited.lock().unwrap().push("c")));
+        assert!(poll_once(b.as_mut()).is_pending());
+        assert!(poll_once(a.as_mut()).is_pending());
+        assert!(poll_once(c.as_mut()).is_pending());
+
+        emit!(source, &1);
+    }
+
+    assert_eq!(visited.into_inner().unwrap(), ["a", "b", "c"]);
+}
@@ -0,0 +1,44 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "alloc")]
+
+use std::{
+    sync::{mpsc, Arc},
+    thread::sleep,
+    time::Duration,
+};
+
+use event_source::{emit, EventSource};
+use futures::executor::ThreadPool;
+
+type Source = EventSource!(&i32);
+
+#[test]
+fn owned_future_spawned_on_thread_pool() {
+    let pool = ThreadPool::new().unwrap();
+    let source: Arc<Source> = Arc::new(EventSource::new());
+
+    let (sender, receiver) = mpsc::channel();
+    let future = source.on_owned(move |&value, flow| {
+        sender.send(value).unwrap();
+        flow.set_done();
+    });
+    let (done_sender, done_receiver) = mpsc::channel();
+    pool.spawn_ok(async move {
+        future.await;
+        done_sender.send(()).unwrap();
+    });
+
+    while source.listener_count() == 0 {
+        sleep(Duration::from_millis(1));
+    }
+    emit!(source, &35);
+
+    assert_eq!(receiver.recv_timeout(Duration::from_secs(5)), Ok(35));
+    assert_eq!(done_receiver.recv_timeout(Duration::from_secs(5)), Ok(()));
+    assert_eq!(source.listener_count(), 0);
+}
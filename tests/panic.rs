@@ -0,0 +1,139 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "std")]
+
+mod common;
+
+use core::pin::pin;
+use std::{
+    backtrace::BacktraceStatus,
+    panic::{self, AssertUnwindSafe},
+    sync::{Arc, Mutex},
+};
+
+use common::poll_once;
+use event_source::{emit, EventSource, ListenerError};
+
+#[test]
+fn panic_report_has_backtrace() {
+    std::env::set_var("RUST_LIB_BACKTRACE", "1");
+
+    let source: EventSource!(&i32) = EventSource::new();
+    let reports = Mutex::new(Vec::new());
+
+    {
+        let mut future = pin!(source.on_panic_report(
+            |_, _| panic!("listener panicked"),
+            |report| reports.lock().unwrap().push(report)
+        ));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        emit!(source, &1);
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+
+    let reports = reports.into_inner().unwrap();
+    assert_eq!(reports.len(), 1);
+    let report = &reports[0];
+    assert_eq!(
+        report.payload.downcast_ref::<&str>(),
+        Some(&"listener panicked")
+    );
+    assert_eq!(report.backtrace.status(), BacktraceStatus::Captured);
+    assert!(!report.backtrace.to_string().is_empty());
+}
+
+#[test]
+fn source_usable_after_listener_panic() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let received = Mutex::new(Vec::new());
+
+    {
+        let mut panicking = pin!(source.on(|_, _| panic!("listener panicked")));
+        assert!(poll_once(panicking.as_mut()).is_pending());
+
+        assert!(panic::catch_unwind(AssertUnwindSafe(|| emit!(source, &1))).is_err());
+    }
+
+    let mut future = pin!(source.on(|&event, _| received.lock().unwrap().push(event)));
+    assert!(poll_once(future.as_mut()).is_pending());
+
+    emit!(source, &2);
+    emit!(source, &3);
+    assert_eq!(*received.lock().unwrap(), [2, 3]);
+}
+
+#[test]
+fn panic_safe_listener_isolated() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let panics = Mutex::new(Vec::new());
+    let received = Mutex::new(Vec::new());
+
+    {
+        let mut panicking = pin!(source.on_panic_safe(
+            |_, _| panic!("listener panicked"),
+            |payload| panics
+                .lock()
+                .unwrap()
+                .push(*payload.downcast::<&str>().unwrap())
+        ));
+        let mut later = pin!(source.on(|&event, _| received.lock().unwrap().push(event)));
+        assert!(poll_once(panicking.as_mut()).is_pending());
+        assert!(poll_once(later.as_mut()).is_pending());
+
+        emit!(source, &1);
+        assert!(poll_once(panicking.as_mut()).is_ready());
+
+        emit!(source, &2);
+    }
+
+    assert_eq!(*panics.lock().unwrap(), ["listener panicked"]);
+    assert_eq!(*received.lock().unwrap(), [1, 2]);
+}
+
+#[test]
+fn emit_next_checked_advances_past_panic() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let received = Mutex::new(Vec::new());
+
+    let mut panicking = pin!(source.on(|_, _| panic!("listener panicked")));
+    let mut later = pin!(source.on(|&event, _| received.lock().unwrap().push(event)));
+    assert!(poll_once(panicking.as_mut()).is_pending());
+    assert!(poll_once(later.as_mut()).is_pending());
+
+    source.with_emitter(|mut emitter| {
+        assert!(emitter.emit_next_checked(&1).is_err());
+        assert_eq!(emitter.position(), 1);
+        assert!(matches!(emitter.emit_next_checked(&1), Ok(Some(()))));
+    });
+
+    assert!(poll_once(panicking.as_mut()).is_ready());
+    assert_eq!(*received.lock().unwrap(), [1]);
+}
+
+#[test]
+fn error_sink_receives_panics_and_errors() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    source.with_error_sink({
+        let errors = errors.clone();
+        move |error| {
+            errors.lock().unwrap().push(match error {
+                ListenerError::Panic(_) => "panic",
+                ListenerError::Error(_) => "error",
+            })
+        }
+    });
+
+    let mut panicking = pin!(source.on_panic_safe(|_, _| panic!("listener panicked"), |_| {}));
+    let mut failing = pin!(source.on_result(|_, _| Err(std::fmt::Error)));
+    assert!(poll_once(panicking.as_mut()).is_pending());
+    assert!(poll_once(failing.as_mut()).is_pending());
+
+    emit!(source, &1);
+    assert_eq!(*errors.lock().unwrap(), ["panic", "error"]);
+}
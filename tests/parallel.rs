@@ -0,0 +1,44 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "rayon")]
+
+mod common;
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use common::poll_once;
+use event_source::EventSource;
+
+#[test]
+fn parallel_emission_reaches_every_listener() {
+    let source: EventSource!(&usize) = EventSource::new();
+    let calls = [const { AtomicUsize::new(0) }; 16];
+    let sum = AtomicUsize::new(0);
+
+    let mut futures = Vec::new();
+    for (i, calls) in calls.iter().enumerate() {
+        let sum = &sum;
+        futures.push(Box::pin(source.on(move |&event, flow| {
+            calls.fetch_add(1, Ordering::Relaxed);
+            sum.fetch_add(event * i, Ordering::Relaxed);
+            if i == 0 {
+                flow.set_done();
+            }
+        })));
+    }
+    for future in &mut futures {
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    assert_eq!(source.emit_parallel(&2), 16);
+    assert!(calls.iter().all(|calls| calls.load(Ordering::Relaxed) == 1));
+    assert_eq!(sum.load(Ordering::Relaxed), 2 * (0..16).sum::<usize>());
+
+    assert!(poll_once(futures[0].as_mut()).is_ready());
+    drop(futures.remove(0));
+    assert_eq!(source.emit_parallel(&1), 15);
+}
@@ -0,0 +1,40 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use core::pin::pin;
+use std::sync::Mutex;
+
+use common::poll_once;
+use event_source::QueuedEventSource;
+
+#[test]
+fn reentrant_emission_delivered_after_current_pass() {
+    let source = QueuedEventSource::<i32>::new();
+    let log = Mutex::new(Vec::new());
+
+    {
+        let mut first = pin!(source.on(|event, _| {
+            log.lock().unwrap().push(("first", event));
+            if event == 1 {
+                source.emit(2);
+            }
+        }));
+        let mut second = pin!(source.on(|event, _| log.lock().unwrap().push(("second", event))));
+        assert!(poll_once(first.as_mut()).is_pending());
+        assert!(poll_once(second.as_mut()).is_pending());
+
+        source.emit(1);
+    }
+
+    assert_eq!(
+        log.into_inner().unwrap(),
+        [("first", 1), ("second", 1), ("first", 2), ("second", 2)]
+    );
+}
@@ -0,0 +1,100 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "alloc")]
+
+mod common;
+
+use core::pin::pin;
+
+use common::poll_once;
+use event_source::ReplayEventSource;
+
+#[test]
+fn resubscribe_replays_missed_generations() {
+    let source = ReplayEventSource::<i32>::new(8);
+    let mut seen = Vec::new();
+
+    {
+        let mut future = pin!(source.resubscribe(0, |event, flow| {
+            seen.push((flow.generation(), event));
+            if seen.len() == 2 {
+                flow.set_done();
+            }
+        }));
+        assert!(poll_once(future.as_mut()).is_pending());
+
+        source.emit(1);
+        source.emit(2);
+        assert!(poll_once(future.as_mut()).is_ready());
+    }
+    let last_seen = seen[1].0;
+
+    for event in 3..=5 {
+        source.emit(event);
+    }
+
+    let mut replayed = Vec::new();
+    {
+        let mut future = pin!(source.resubscribe(last_seen, |event, flow| {
+            replayed.push((flow.generation(), event));
+        }));
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    assert_eq!(seen[0].0 + 1, last_seen);
+    assert_eq!(
+        replayed,
+        [(last_seen + 1, 3), (last_seen + 2, 4), (last_seen + 3, 5)]
+    );
+}
+
+#[test]
+fn byte_budget_evicts_oldest_events() {
+    let source = ReplayEventSource::<&'static str>::with_byte_budget(8, |event| event.len());
+    for event in ["abc", "de", "fghi", "jk"] {
+        source.emit(event);
+    }
+
+    let mut replayed = Vec::new();
+    {
+        let mut future = pin!(source.resubscribe(0, |event, _| replayed.push(event)));
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    assert_eq!(replayed, ["de", "fghi", "jk"]);
+    assert!(replayed.iter().map(|event| event.len()).sum::<usize>() <= 8);
+}
+
+#[test]
+fn drain_buffer_empties_history() {
+    let source = ReplayEventSource::<i32>::new(8);
+    for event in 1..=3 {
+        source.emit(event);
+    }
+
+    assert_eq!(source.drain_buffer(), [1, 2, 3]);
+    assert_eq!(source.drain_buffer(), []);
+
+    source.emit(4);
+    assert_eq!(source.drain_buffer(), [4]);
+}
+
+#[test]
+fn try_next_takes_oldest_event() {
+    let source = ReplayEventSource::<&'static str>::with_byte_budget(8, |event| event.len());
+    assert_eq!(source.try_next(), None);
+
+    for event in ["abc", "de"] {
+        source.emit(event);
+    }
+    assert_eq!(source.try_next(), Some("abc"));
+
+    source.emit("fghij");
+    assert_eq!(source.try_next(), Some("de"));
+    assert_eq!(source.try_next(), Some("fghij"));
+    assert_eq!(source.try_next(), None);
+}
@@ -0,0 +1,118 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+mod common;
+
+use core::{
+    future::Future,
+    pin::pin,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::poll_once;
+use event_source::{EventSource, ShardedEventSource};
+
+const LISTENERS: usize = 64;
+const EMITTERS: usize = 8;
+const EVENTS: usize = 20000;
+
+#[test]
+fn every_shard_listener_notified_once() {
+    let source: ShardedEventSource!(3; &i32) = ShardedEventSource::new();
+    let counts = [const { AtomicUsize::new(0) }; 7];
+
+    let mut futures = counts.each_ref().map(|count| {
+        Box::pin(source.on(move |_, _| {
+            count.fetch_add(1, Ordering::Relaxed);
+        }))
+    });
+    for future in &mut futures {
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    assert_eq!(source.listener_count(), 7);
+    assert_eq!(
+        source
+            .shards()
+            .each_ref()
+            .map(|shard| shard.listener_count()),
+        [3, 2, 2]
+    );
+
+    assert_eq!(source.emit_owned(&1), 7);
+    assert_eq!(source.emit_owned(&2), 7);
+    assert!(counts
+        .iter()
+        .all(|count| count.load(Ordering::Relaxed) == 2));
+}
+
+/// Time emissions from concurrent emitters while a thread keeps adding and removing listeners
+fn contended_emission<'a, L, E>(listen: L, emit: E) -> Duration
+where
+    L: Fn(&'a AtomicUsize) -> core::pin::Pin<Box<dyn Future<Output = ()> + Send + 'a>> + Sync,
+    E: Fn(usize) + Sync,
+{
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let mut futures = (0..LISTENERS).map(|_| listen(&CALLS)).collect::<Vec<_>>();
+    for future in &mut futures {
+        assert!(poll_once(future.as_mut()).is_pending());
+    }
+
+    let start = Instant::now();
+    thread::scope(|scope| {
+        for _ in 0..EMITTERS {
+            scope.spawn(|| {
+                for event in 0..EVENTS {
+                    emit(event);
+                }
+            });
+        }
+
+        scope.spawn(|| {
+            for _ in 0..EVENTS {
+                let mut future = pin!(listen(&CALLS));
+                assert!(poll_once(future.as_mut()).is_pending());
+            }
+        });
+    });
+
+    start.elapsed()
+}
+
+#[test]
+#[ignore = "benchmark, run with `cargo test --release --test sharded -- --ignored --nocapture`"]
+fn bench_sharded_emission() {
+    let single: EventSource!(&usize) = EventSource::new();
+    let sharded: ShardedEventSource!(8; &usize) = ShardedEventSource::new();
+
+    let single_time = contended_emission(
+        |calls| {
+            Box::pin(single.on(move |_, _| {
+                calls.fetch_add(1, Ordering::Relaxed);
+            }))
+        },
+        |event| {
+            single.emit_owned(&event);
+        },
+    );
+    let sharded_time = contended_emission(
+        |calls| {
+            Box::pin(sharded.on(move |_, _| {
+                calls.fetch_add(1, Ordering::Relaxed);
+            }))
+        },
+        |event| {
+            sharded.emit_owned(&event);
+        },
+    );
+
+    println!("single: {single_time:?}, sharded: {sharded_time:?}");
+}
@@ -0,0 +1,101 @@
+/*
+ * Created on Wed Oct 14 2026
+ *
+ * Copyright (c) storycraft. Licensed under the MIT Licence.
+ */
+
+#![cfg(feature = "alloc")]
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc,
+};
+
+use event_source::{emit, EventSource};
+
+#[test]
+fn subscription_removed_on_drop() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = Arc::new(AtomicUsize::new(0));
+
+    let subscription = source.subscribe({
+        let called = called.clone();
+        move |_, _| {
+            called.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+    emit!(source, &1);
+    emit!(source, &2);
+    assert_eq!(called.load(Ordering::Relaxed), 2);
+
+    drop(subscription);
+    assert_eq!(source.listener_count(), 0);
+
+    emit!(source, &3);
+    assert_eq!(called.load(Ordering::Relaxed), 2);
+}
+
+#[test]
+fn subscribe_once_called_once() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = Arc::new(AtomicUsize::new(0));
+
+    let _subscription = source.subscribe_once({
+        let called = called.clone();
+        move |&event| {
+            assert_eq!(event, 1);
+            called.fetch_add(1, Ordering::Relaxed);
+        }
+    });
+    emit!(source, &1);
+    emit!(source, &2);
+
+    assert_eq!(called.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn subscribe_once_cancelled_on_drop() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = Arc::new(AtomicUsize::new(0));
+
+    drop(source.subscribe_once({
+        let called = called.clone();
+        move |_| {
+            called.fetch_add(1, Ordering::Relaxed);
+        }
+    }));
+    emit!(source, &1);
+
+    assert_eq!(called.load(Ordering::Relaxed), 0);
+}
+
+#[test]
+fn leaked_subscription_keeps_listener() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let called = Arc::new(AtomicUsize::new(0));
+
+    {
+        let called = called.clone();
+        core::mem::forget(source.subscribe(move |_, _| {
+            called.fetch_add(1, Ordering::Relaxed);
+        }));
+    }
+    emit!(source, &1);
+
+    assert_eq!(called.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn scoped_subscriptions_removed_on_exit() {
+    let source: EventSource!(&i32) = EventSource::new();
+    let mut received = Vec::new();
+
+    source.scope(|scope| {
+        scope.subscribe(|&event, _| received.push(event));
+        emit!(source, &1);
+    });
+    assert_eq!(source.listener_count(), 0);
+
+    emit!(source, &2);
+    assert_eq!(received, [1]);
+}